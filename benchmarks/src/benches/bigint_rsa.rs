@@ -0,0 +1,43 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_bigint::BigUint;
+use risc0_circuit_bigint::rsa::RSA_256_X2;
+use risc0_circuit_bigint_test_methods::{RSA_ELF, RSA_ID};
+use risc0_zkvm::serde::to_vec;
+
+use crate::Job;
+
+// A 256-bit modulus, signature and message satisfying S^65537 = M (mod N).
+const N: &str = "9c98f9aacfc0b73c916a824db9afe39673dcb56c42dffe9de5b86d5748aca4d5";
+const S: &str = "de67116c809a5cc876cebb5e8c72d998f983a4d61b499dd9ae23b789a7183677";
+const M: &str = "1fb897fac8aa8870b936631d3af1a17930c8af0ca4376b3056677ded52adf5aa";
+
+// The guest proves its claims with the bigint ZKRs, which the prover resolves
+// as assumptions when it lifts the session to a succinct receipt. These are
+// registered by [crate::run_jobs].
+pub fn new_jobs() -> Vec<Job> {
+    let claim = [N, S, M].map(|hex| BigUint::parse_bytes(hex.as_bytes(), 16).unwrap());
+    let mut jobs = Vec::new();
+    for claims in [1, RSA_256_X2.iters] {
+        jobs.push(Job::new(
+            format!("bigint_rsa-{claims}"),
+            RSA_ELF,
+            RSA_ID.into(),
+            to_vec(&vec![claim.clone(); claims]).unwrap(),
+            claims,
+        ));
+    }
+    jobs
+}
@@ -17,13 +17,21 @@
 pub mod benches;
 
 use std::{
+    collections::BTreeMap,
     path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
     time::{Duration, Instant},
 };
 
 use human_repr::{HumanCount, HumanDuration, HumanThroughput};
+use risc0_circuit_bigint::zkr::register_zkrs;
 use risc0_zkvm::{
-    get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ProverOpts, Session, VerifierContext,
+    get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ProverOpts, Receipt, Session,
+    VerifierContext,
 };
 use serde::Serialize;
 use serde_with::{serde_as, DurationNanoSeconds};
@@ -56,12 +64,20 @@ pub struct Metrics {
     pub output_bytes: usize,
     #[tabled(display_with = "display_bytes")]
     pub proof_bytes: usize,
+    /// Peak resident set size of the whole process while proving. When jobs run
+    /// concurrently this includes every prover running at the same time.
+    #[tabled(display_with = "display_memory")]
+    pub peak_rss_bytes: u64,
 }
 
 fn display_bytes(bytes: &usize) -> String {
     bytes.human_count_bytes().to_string()
 }
 
+fn display_memory(bytes: &u64) -> String {
+    bytes.human_count_bytes().to_string()
+}
+
 fn display_cycles(cycles: &u64) -> String {
     cycles.human_count_bare().to_string()
 }
@@ -87,17 +103,202 @@ impl Metrics {
             user_cycles: 0,
             output_bytes: 0,
             proof_bytes: 0,
+            peak_rss_bytes: 0,
             speed: 0.0,
         }
     }
+
+    /// Render this job's metrics in the Prometheus text exposition format.
+    ///
+    /// See [to_prometheus] for the layout and labels.
+    pub fn to_prometheus(&self, labels: &[(&str, &str)]) -> String {
+        to_prometheus(std::slice::from_ref(self), labels)
+    }
+}
+
+/// A Prometheus metric family exported for every [Metrics] row.
+struct PrometheusFamily {
+    name: &'static str,
+    help: &'static str,
+    kind: &'static str,
+    value: fn(&Metrics) -> f64,
+}
+
+// Cycle counts only ever accumulate while a job runs, so they are exported as counters with the
+// conventional `_total` suffix. Everything else is a measurement of a single run and is a gauge.
+const PROMETHEUS_FAMILIES: [PrometheusFamily; 11] = [
+    PrometheusFamily {
+        name: "risc0_size",
+        help: "Size of the benchmark input, in benchmark-specific units.",
+        kind: "gauge",
+        value: |m| m.size as f64,
+    },
+    PrometheusFamily {
+        name: "risc0_speed_hz",
+        help: "Benchmark size processed per second of execution and proving.",
+        kind: "gauge",
+        value: |m| m.speed as f64,
+    },
+    PrometheusFamily {
+        name: "risc0_exec_duration_seconds",
+        help: "Time spent executing the guest.",
+        kind: "gauge",
+        value: |m| m.exec_duration.as_secs_f64(),
+    },
+    PrometheusFamily {
+        name: "risc0_proof_duration_seconds",
+        help: "Time spent proving the session.",
+        kind: "gauge",
+        value: |m| m.proof_duration.as_secs_f64(),
+    },
+    PrometheusFamily {
+        name: "risc0_total_duration_seconds",
+        help: "Time spent executing and proving.",
+        kind: "gauge",
+        value: |m| m.total_duration.as_secs_f64(),
+    },
+    PrometheusFamily {
+        name: "risc0_verify_duration_seconds",
+        help: "Time spent verifying the receipt.",
+        kind: "gauge",
+        value: |m| m.verify_duration.as_secs_f64(),
+    },
+    PrometheusFamily {
+        name: "risc0_cycles_total",
+        help: "Total cycles of the session, including paging and padding.",
+        kind: "counter",
+        value: |m| m.total_cycles as f64,
+    },
+    PrometheusFamily {
+        name: "risc0_user_cycles_total",
+        help: "Cycles spent executing guest instructions.",
+        kind: "counter",
+        value: |m| m.user_cycles as f64,
+    },
+    PrometheusFamily {
+        name: "risc0_output_bytes",
+        help: "Size of the journal.",
+        kind: "gauge",
+        value: |m| m.output_bytes as f64,
+    },
+    PrometheusFamily {
+        name: "risc0_proof_bytes",
+        help: "Size of the receipt seal.",
+        kind: "gauge",
+        value: |m| m.proof_bytes as f64,
+    },
+    PrometheusFamily {
+        name: "risc0_peak_rss_bytes",
+        help: "Peak resident set size of the process while proving.",
+        kind: "gauge",
+        value: |m| m.peak_rss_bytes as f64,
+    },
+];
+
+/// Render `metrics` in the Prometheus text exposition format.
+///
+/// Each metric family is written once, with `# HELP` and `# TYPE` lines followed by one sample
+/// per job. Every sample is labelled with the job name followed by `labels`.
+pub fn to_prometheus(metrics: &[Metrics], labels: &[(&str, &str)]) -> String {
+    let job_labels: Vec<String> = metrics
+        .iter()
+        .map(|m| {
+            [("job", m.name.as_str())]
+                .iter()
+                .chain(labels)
+                .map(|(key, value)| format!("{key}=\"{}\"", escape_label_value(value)))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+
+    let mut out = String::new();
+    for family in PROMETHEUS_FAMILIES.iter() {
+        let name = family.name;
+        out += &format!("# HELP {name} {}\n", family.help);
+        out += &format!("# TYPE {name} {}\n", family.kind);
+        for (m, labels) in metrics.iter().zip(&job_labels) {
+            out += &format!("{name}{{{labels}}} {}\n", (family.value)(m));
+        }
+    }
+    out
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// How [run_jobs] prints the final summary of all metrics to stdout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// A table drawn with box-drawing characters.
+    #[default]
+    Pretty,
+    /// A Markdown table, suitable for pasting into pull-request comments.
+    Markdown,
+    /// CSV rows with a header, using the same columns as the CSV output file.
+    Csv,
+}
+
+/// Render `metrics` as a summary in the given `format`.
+pub fn render_metrics(metrics: &[Metrics], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Pretty => Table::new(metrics).with(Style::modern()).to_string(),
+        OutputFormat::Markdown => Table::new(metrics).with(Style::markdown()).to_string(),
+        OutputFormat::Csv => {
+            let mut out = csv::WriterBuilder::new().from_writer(vec![]);
+            for row in metrics {
+                out.serialize(row).expect("Could not serialize");
+            }
+            String::from_utf8(out.into_inner().unwrap()).unwrap()
+        }
+    }
+}
+
+/// An error that stopped a benchmark [Job] from producing [Metrics].
+#[derive(Debug)]
+pub enum BenchError {
+    /// Proving did not finish within the timeout set by [Job::with_prove_timeout].
+    ProveTimeout { name: String, timeout: Duration },
+}
+
+impl std::fmt::Display for BenchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BenchError::ProveTimeout { name, timeout } => {
+                write!(f, "{name}: proving did not finish within {timeout:?}")
+            }
+        }
+    }
 }
 
+impl std::error::Error for BenchError {}
+
+#[derive(Clone)]
 pub struct Job {
     name: String,
     elf: Vec<u8>,
     input: Vec<u32>,
     image_id: Digest,
     size: usize,
+    prove_timeout: Option<Duration>,
+}
+
+// Progress reported by the thread running a [Job].
+enum Progress {
+    Executed {
+        total_cycles: u64,
+        user_cycles: u64,
+        duration: Duration,
+    },
+    Proved {
+        receipt: Receipt,
+        duration: Duration,
+        peak_rss_bytes: u64,
+    },
 }
 
 impl Job {
@@ -108,9 +309,21 @@ impl Job {
             input,
             image_id,
             size,
+            prove_timeout: None,
         }
     }
 
+    /// Fail with [BenchError::ProveTimeout] if proving takes longer than `timeout`.
+    ///
+    /// The prover cannot be interrupted, so on timeout it is left running on its
+    /// own thread until the process exits. [run_jobs] therefore stops starting
+    /// new jobs once one has timed out, since their measurements would be
+    /// skewed by the abandoned prover.
+    pub fn with_prove_timeout(mut self, timeout: Duration) -> Self {
+        self.prove_timeout = Some(timeout);
+        self
+    }
+
     fn exec_compute(&self) -> (Session, Duration) {
         let env = ExecutorEnv::builder()
             .write_slice(&self.input)
@@ -123,64 +336,416 @@ impl Job {
         (session, elapsed)
     }
 
-    fn run(&self) -> Metrics {
-        let mut metrics = Metrics::new(self.name.clone(), self.size);
-
-        let (session, duration) = self.exec_compute();
-
-        metrics.total_cycles = session.total_cycles;
-        metrics.user_cycles = session.user_cycles;
-        metrics.exec_duration = duration;
-
+    fn prove(session: &Session) -> Progress {
         let prover = get_prover_server(&ProverOpts::succinct()).unwrap();
         let ctx = VerifierContext::default();
 
         let start = Instant::now();
-        let receipt = prover.prove_session(&ctx, &session).unwrap().receipt;
-        metrics.proof_duration = start.elapsed();
+        let (receipt, peak_rss_bytes) =
+            with_peak_rss(|| prover.prove_session(&ctx, session).unwrap().receipt);
+        Progress::Proved {
+            receipt,
+            duration: start.elapsed(),
+            peak_rss_bytes,
+        }
+    }
+
+    fn executed(session: &Session, duration: Duration) -> Progress {
+        Progress::Executed {
+            total_cycles: session.total_cycles,
+            user_cycles: session.user_cycles,
+            duration,
+        }
+    }
+
+    // Execute and prove on a separate thread, so that proving can be abandoned
+    // on timeout. Neither the session nor the prover can be moved across
+    // threads, so both steps run there.
+    fn spawn(&self) -> mpsc::Receiver<Progress> {
+        let (tx, rx) = mpsc::channel();
+        let job = self.clone();
+        thread::spawn(move || {
+            let (session, duration) = job.exec_compute();
+            if tx.send(Self::executed(&session, duration)).is_err() {
+                return;
+            }
+            let _ = tx.send(Self::prove(&session));
+        });
+        rx
+    }
+
+    pub fn run(&self) -> Result<Metrics, BenchError> {
+        let mut metrics = Metrics::new(self.name.clone(), self.size);
+
+        let (executed, proved) = match self.prove_timeout {
+            None => {
+                let (session, duration) = self.exec_compute();
+                (Self::executed(&session, duration), Self::prove(&session))
+            }
+            Some(timeout) => wait_for_proof(&self.name, self.spawn(), timeout)?,
+        };
+
+        let (
+            Progress::Executed {
+                total_cycles,
+                user_cycles,
+                duration: exec_duration,
+            },
+            Progress::Proved {
+                receipt,
+                duration: proof_duration,
+                peak_rss_bytes,
+            },
+        ) = (executed, proved)
+        else {
+            unreachable!("a job reports proving after execution");
+        };
+        metrics.total_cycles = total_cycles;
+        metrics.user_cycles = user_cycles;
+        metrics.exec_duration = exec_duration;
+        metrics.proof_duration = proof_duration;
+        metrics.peak_rss_bytes = peak_rss_bytes;
 
         metrics.total_duration = metrics.exec_duration + metrics.proof_duration;
         metrics.speed = self.size as f32 / metrics.total_duration.as_secs_f32();
         metrics.output_bytes = receipt.journal.bytes.len();
-        metrics.proof_bytes = receipt.inner.succinct().unwrap().seal_size();
+        metrics.proof_bytes = receipt.seal_size();
 
         let start = Instant::now();
         receipt.verify(self.image_id).unwrap();
         metrics.verify_duration = start.elapsed();
 
-        metrics
+        Ok(metrics)
     }
 }
 
-pub fn run_jobs(out_path: &Path, jobs: Vec<Job>) -> Vec<Metrics> {
+/// Wait for the execution and then the proof reported on `progress`, giving up on the proof
+/// after `timeout`.
+fn wait_for_proof(
+    name: &str,
+    progress: mpsc::Receiver<Progress>,
+    timeout: Duration,
+) -> Result<(Progress, Progress), BenchError> {
+    let executed = progress
+        .recv()
+        .unwrap_or_else(|_| panic!("{name}: execution failed"));
+    let proved = progress.recv_timeout(timeout).map_err(|err| match err {
+        mpsc::RecvTimeoutError::Timeout => BenchError::ProveTimeout {
+            name: name.to_string(),
+            timeout,
+        },
+        mpsc::RecvTimeoutError::Disconnected => panic!("{name}: proving failed"),
+    })?;
+    Ok((executed, proved))
+}
+
+/// Returns the resident set size of this process, or 0 if it is unavailable
+/// on this platform.
+fn current_rss_bytes() -> u64 {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix("VmRSS:"))
+                .and_then(|value| {
+                    value
+                        .trim()
+                        .trim_end_matches("kB")
+                        .trim()
+                        .parse::<u64>()
+                        .ok()
+                })
+        })
+        .map(|kb| kb * 1024)
+        .unwrap_or(0)
+}
+
+/// Runs `f` while sampling the resident set size of this process, returning
+/// the result of `f` along with the peak RSS observed.
+fn with_peak_rss<T>(f: impl FnOnce() -> T) -> (T, u64) {
+    const SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+    let done = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let sampler = scope.spawn(|| {
+            let mut peak = current_rss_bytes();
+            while !done.load(Ordering::Relaxed) {
+                peak = peak.max(current_rss_bytes());
+                thread::sleep(SAMPLE_INTERVAL);
+            }
+            peak
+        });
+        let result = f();
+        done.store(true, Ordering::Relaxed);
+        let peak = sampler.join().unwrap().max(current_rss_bytes());
+        (result, peak)
+    })
+}
+
+/// Runs `run` over `items` using up to `concurrency` worker threads, handing
+/// each result to `sink` in the same order as `items`.
+fn run_ordered<T, R>(
+    items: &[T],
+    concurrency: usize,
+    run: impl Fn(&T) -> R + Sync,
+    mut sink: impl FnMut(&T, R),
+) where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..concurrency.clamp(1, items.len().max(1)) {
+            let tx = tx.clone();
+            let (next, run) = (&next, &run);
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(idx) else {
+                    break;
+                };
+                if tx.send((idx, run(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        // Buffer results that complete out of order until their turn comes.
+        let mut pending = BTreeMap::new();
+        let mut cursor = 0;
+        for (idx, result) in rx {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&cursor) {
+                sink(&items[cursor], result);
+                cursor += 1;
+            }
+        }
+    });
+}
+
+/// Run `jobs`, writing each job's [Metrics] to the CSV file at `out_path`.
+///
+/// With `concurrency` greater than one, jobs share the machine: their timings
+/// are contended and [Metrics::peak_rss_bytes] is measured for the whole
+/// process, so use a concurrency of one for representative numbers.
+pub fn run_jobs(
+    out_path: &Path,
+    prom_path: Option<&Path>,
+    jobs: Vec<Job>,
+    concurrency: usize,
+    format: OutputFormat,
+) -> Vec<Metrics> {
     tracing::info!("");
     tracing::info!(
-        "Running {} jobs; saving output to {}",
+        "Running {} jobs with concurrency {concurrency}; saving output to {}",
         jobs.len(),
         out_path.display()
     );
 
+    // Needed to prove guests that use the bigint accelerator.
+    register_zkrs();
+
+    if concurrency > 1 {
+        tracing::warn!(
+            "Running jobs concurrently: timings are contended and peak RSS covers all jobs in flight"
+        );
+    }
+
     let mut out = csv::WriterBuilder::new().from_path(out_path).unwrap();
 
     let mut all_metrics = Vec::new();
 
-    for job in jobs {
-        println!("Benchmarking {}", job.name);
-
-        let metrics = job.run();
-        println!(" + {}", display_speed(&metrics.speed));
-        out.serialize(&metrics).expect("Could not serialize");
-        out.flush().expect("Could not flush");
+    // Set once a job times out; see [Job::with_prove_timeout].
+    let aborted = AtomicBool::new(false);
+    run_ordered(
+        &jobs,
+        concurrency,
+        |job| {
+            if aborted.load(Ordering::Relaxed) {
+                return None;
+            }
+            let result = job.run();
+            if matches!(result, Err(BenchError::ProveTimeout { .. })) {
+                aborted.store(true, Ordering::Relaxed);
+            }
+            Some(result)
+        },
+        |job, result| match result {
+            None => println!(" - {}: skipped after a prove timeout", job.name),
+            Some(Ok(metrics)) => {
+                println!(" + {}: {}", job.name, display_speed(&metrics.speed));
+                out.serialize(&metrics).expect("Could not serialize");
+                out.flush().expect("Could not flush");
 
-        all_metrics.push(metrics);
-    }
+                all_metrics.push(metrics);
+            }
+            Some(Err(err)) => {
+                println!(" - {err}");
+                tracing::error!("{err}");
+            }
+        },
+    );
 
     out.flush().expect("Could not flush");
     tracing::info!("Finished {} jobs", all_metrics.len());
 
-    let mut table = Table::new(&all_metrics);
-    table.with(Style::modern());
-    println!("{table}");
+    if let Some(prom_path) = prom_path {
+        std::fs::write(prom_path, to_prometheus(&all_metrics, &[]))
+            .expect("Could not write Prometheus metrics");
+    }
+
+    println!("{}", render_metrics(&all_metrics, format));
 
     all_metrics
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, thread, time::Duration};
+
+    use risc0_zkvm::ExitCode;
+
+    use super::{
+        benches::bigint_rsa, register_zkrs, render_metrics, run_ordered, to_prometheus,
+        wait_for_proof, BenchError, Job, Metrics, OutputFormat, Progress,
+    };
+
+    #[test]
+    fn run_ordered_preserves_input_order() {
+        let jobs: Vec<u64> = (0..8).collect();
+        let mut out = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(vec![]);
+
+        // Earlier jobs take longer so that they finish out of order.
+        run_ordered(
+            &jobs,
+            2,
+            |job| {
+                thread::sleep(Duration::from_millis(10 * (8 - job)));
+                *job
+            },
+            |_, result| out.serialize(result).unwrap(),
+        );
+
+        let csv = String::from_utf8(out.into_inner().unwrap()).unwrap();
+        let rows: Vec<u64> = csv.lines().map(|row| row.parse().unwrap()).collect();
+        assert_eq!(rows, jobs);
+    }
+
+    #[test]
+    fn prometheus_families() {
+        let mut fib = Metrics::new("fib \"big\"".to_string(), 10);
+        fib.total_cycles = 1 << 20;
+        fib.proof_bytes = 1234;
+        fib.exec_duration = Duration::from_millis(1500);
+        let sha = Metrics::new("sha".to_string(), 20);
+
+        let prom = to_prometheus(&[fib, sha], &[("host", "ci")]);
+        let fib_labels = r#"{job="fib \"big\"",host="ci"}"#;
+        let sha_labels = r#"{job="sha",host="ci"}"#;
+        assert!(prom.contains(&format!(
+            "# TYPE risc0_cycles_total counter\nrisc0_cycles_total{fib_labels} 1048576\n"
+        )));
+        assert!(prom.contains(&format!("risc0_proof_bytes{fib_labels} 1234\n")));
+        assert!(prom.contains(&format!("risc0_exec_duration_seconds{fib_labels} 1.5\n")));
+        assert!(prom.contains(&format!(
+            "# TYPE risc0_size gauge\nrisc0_size{fib_labels} 10\nrisc0_size{sha_labels} 20\n"
+        )));
+
+        // Each family is declared once and its samples are contiguous.
+        let mut families: Vec<&str> = Vec::new();
+        for line in prom.lines() {
+            if let Some(decl) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = decl.split_once(' ').unwrap();
+                let expected = if name.ends_with("_total") {
+                    "counter"
+                } else {
+                    "gauge"
+                };
+                assert_eq!(kind, expected, "{name}");
+                assert!(!families.contains(&name), "{name} declared twice");
+                families.push(name);
+                continue;
+            }
+            if line.starts_with("# HELP ") {
+                continue;
+            }
+            let (sample, value) = line.rsplit_once(' ').unwrap();
+            let (name, labels) = sample.split_once('{').unwrap();
+            assert_eq!(Some(&name), families.last());
+            assert!(labels.ends_with('}'));
+            value.parse::<f64>().unwrap();
+        }
+        assert_eq!(families.len(), 11);
+    }
+
+    #[test]
+    fn metrics_to_prometheus() {
+        let mut sha = Metrics::new("sha".to_string(), 20);
+        sha.user_cycles = 4096;
+        let labels = [("host", "ci")];
+        let prom = sha.to_prometheus(&labels);
+        assert_eq!(prom, to_prometheus(&[sha], &labels));
+        assert!(prom.contains("risc0_user_cycles_total{job=\"sha\",host=\"ci\"} 4096\n"));
+    }
+
+    #[test]
+    fn render_metrics_formats() {
+        let metrics = vec![
+            Metrics::new("fib".to_string(), 10),
+            Metrics::new("sha".to_string(), 20),
+        ];
+
+        let markdown = render_metrics(&metrics, OutputFormat::Markdown);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("| name "));
+        assert!(lines[1].starts_with("|---"));
+        assert!(lines[2].starts_with("| fib "));
+
+        let csv = render_metrics(&metrics, OutputFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("name,size,speed,"));
+        assert!(lines[1].starts_with("fib,10,"));
+        assert!(lines[2].starts_with("sha,20,"));
+    }
+
+    #[test]
+    fn prove_timeout() {
+        // A stub job that finishes executing but never reports a proof.
+        let (tx, rx) = mpsc::channel();
+        tx.send(Progress::Executed {
+            total_cycles: 0,
+            user_cycles: 0,
+            duration: Duration::ZERO,
+        })
+        .unwrap();
+
+        let err = wait_for_proof("stub", rx, Duration::from_millis(1))
+            .err()
+            .unwrap();
+        assert!(
+            matches!(&err, BenchError::ProveTimeout { name, .. } if name == "stub"),
+            "{err}"
+        );
+        drop(tx);
+    }
+
+    #[test]
+    fn bigint_rsa_job() {
+        register_zkrs();
+        let job = bigint_rsa::new_jobs().remove(0);
+        let (session, _) = job.exec_compute();
+        assert_eq!(session.exit_code, ExitCode::Halted(0), "{}", job.name);
+
+        // The bigint claims are only resolved by lifting to a succinct receipt.
+        let Progress::Proved { receipt, .. } = Job::prove(&session) else {
+            unreachable!("Job::prove reports a proof");
+        };
+        receipt.verify(job.image_id).unwrap();
+    }
+}
@@ -14,11 +14,11 @@
 
 // This is based on zk-benchmarking: https://github.com/delendum-xyz/zk-benchmarking
 
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use clap::{Parser, Subcommand};
 use enum_iterator::Sequence;
-use risc0_benchmark::{benches::*, run_jobs, Job};
+use risc0_benchmark::{benches::*, run_jobs, Job, OutputFormat};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser)]
@@ -28,6 +28,25 @@ struct Cli {
     #[arg(long, value_name = "FILE", default_value = "metrics.csv")]
     out: PathBuf,
 
+    /// Also write the metrics to this file in Prometheus text format
+    #[arg(long, value_name = "FILE")]
+    prom: Option<PathBuf>,
+
+    /// Number of jobs to run in parallel. Values above 1 contend for the machine, so timings
+    /// and peak RSS are no longer per job.
+    #[arg(long, default_value_t = 1)]
+    concurrency: usize,
+
+    /// Give up on a job if proving takes longer than this many seconds.
+    ///
+    /// The abandoned prover cannot be stopped, so no further jobs are started after a timeout.
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<u64>,
+
+    /// Format of the summary printed to stdout
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    format: OutputFormat,
+
     #[command(subcommand)]
     command: Option<Command>,
 }
@@ -39,6 +58,7 @@ enum Command {
     BigBlake3,
     BigKeccak,
     BigSha2,
+    BigintRsa,
     EcdsaVerify,
     Ed25519Verify,
     Fibonacci,
@@ -61,6 +81,7 @@ impl Command {
             Command::BigBlake3 => big_blake3::new_jobs(),
             Command::BigKeccak => big_keccak::new_jobs(),
             Command::BigSha2 => big_sha2::new_jobs(),
+            Command::BigintRsa => bigint_rsa::new_jobs(),
             Command::EcdsaVerify => ecdsa_verify::new_jobs(),
             Command::Ed25519Verify => ed25519_verify::new_jobs(),
             Command::Fibonacci => fibonacci::new_jobs(),
@@ -85,5 +106,19 @@ fn main() {
 
     let cli = Cli::parse();
     let cmd = cli.command.unwrap_or(Command::All);
-    run_jobs(&cli.out, cmd.get_jobs());
+    let mut jobs = cmd.get_jobs();
+    if let Some(timeout) = cli.timeout {
+        let timeout = Duration::from_secs(timeout);
+        jobs = jobs
+            .into_iter()
+            .map(|job| job.with_prove_timeout(timeout))
+            .collect();
+    }
+    run_jobs(
+        &cli.out,
+        cli.prom.as_deref(),
+        jobs,
+        cli.concurrency,
+        cli.format,
+    );
 }
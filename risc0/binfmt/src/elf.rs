@@ -14,7 +14,7 @@
 
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, string::String};
 
 use anyhow::{anyhow, bail, Context, Result};
 use elf::{endian::LittleEndian, file::Class, ElfBytes};
@@ -29,6 +29,116 @@ pub struct Program {
     pub image: BTreeMap<u32, u32>,
 }
 
+/// Diagnostic information read from a guest ELF, kept apart from the [Program] loaded from it.
+///
+/// [Program] only has public fields, so downstream code builds it with struct literals. Adding
+/// fields to it would break that code, so this information lives here instead. To get both,
+/// load the same ELF bytes twice:
+///
+/// ```rust,ignore
+/// let program = Program::load_elf(elf, GUEST_MAX_MEM as u32)?;
+/// let info = ElfInfo::load(elf)?;
+/// println!("entry point is in {:?}", info.symbol_at(program.entry));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ElfInfo {
+    /// Symbols from the ELF symbol table, as a map from address to size and name.
+    symbols: BTreeMap<u32, (u32, String)>,
+
+    /// ISA extensions recorded in the ELF's RISC-V attributes.
+    extensions: ElfExtensions,
+}
+
+/// RISC-V ISA extensions that a guest ELF was compiled to use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ElfExtensions {
+    /// Integer multiplication and division (M).
+    pub m: bool,
+    /// Atomic instructions (A).
+    pub a: bool,
+    /// Single-precision floating point (F).
+    pub f: bool,
+    /// Double-precision floating point (D).
+    pub d: bool,
+    /// Compressed instructions (C).
+    pub c: bool,
+}
+
+impl ElfExtensions {
+    /// Parse the single-letter extensions from an ISA string such as `rv32i2p1_m2p0_a2p1`.
+    fn from_arch(arch: &str) -> Self {
+        let mut exts = Self::default();
+        let Some(rest) = arch
+            .strip_prefix("rv32")
+            .or_else(|| arch.strip_prefix("rv64"))
+        else {
+            return exts;
+        };
+        for (idx, token) in rest.split('_').enumerate() {
+            // Multi-letter extensions (e.g. `zicsr`) are always separated by an underscore.
+            if idx > 0 && token.starts_with(['z', 's', 'x']) {
+                continue;
+            }
+            let mut prev_digit = false;
+            for ch in token.chars() {
+                match ch {
+                    // The `p` separating major and minor versions, e.g. `2p1`.
+                    'p' if prev_digit => {}
+                    'g' => {
+                        exts.m = true;
+                        exts.a = true;
+                        exts.f = true;
+                        exts.d = true;
+                    }
+                    'm' => exts.m = true,
+                    'a' => exts.a = true,
+                    'f' => exts.f = true,
+                    'd' => exts.d = true,
+                    'c' => exts.c = true,
+                    _ => {}
+                }
+                prev_digit = ch.is_ascii_digit();
+            }
+        }
+        exts
+    }
+}
+
+impl ElfInfo {
+    /// Read the symbol table and RISC-V attributes of an ELF file.
+    ///
+    /// A missing or malformed symbol table or attributes section yields no symbols or extensions
+    /// rather than an error, since they are only used for diagnostics.
+    pub fn load(input: &[u8]) -> Result<ElfInfo> {
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(input)
+            .map_err(|err| anyhow!("Elf parse error: {err}"))?;
+        Ok(ElfInfo {
+            symbols: load_symbols(&elf),
+            extensions: load_extensions(&elf),
+        })
+    }
+
+    /// Return the ISA extensions the ELF was compiled to use.
+    ///
+    /// This is read from the `.riscv.attributes` section. ELFs without that section report no
+    /// extensions.
+    pub fn required_extensions(&self) -> ElfExtensions {
+        self.extensions
+    }
+
+    /// Return the name of the symbol covering `addr`, if the ELF had a symbol table.
+    ///
+    /// This is intended for diagnostics, e.g. to annotate a pc with the name of the function it
+    /// falls in.
+    pub fn symbol_at(&self, addr: u32) -> Option<&str> {
+        self.symbols
+            .range(..=addr)
+            .rev()
+            .find(|(start, (size, _))| addr - *start < (*size).max(1))
+            .map(|(_, (_, name))| name.as_str())
+    }
+}
+
 impl Program {
     /// Initialize a RISC Zero Program from an appropriate ELF file
     pub fn load_elf(input: &[u8], max_mem: u32) -> Result<Program> {
@@ -106,3 +216,206 @@ impl Program {
         Ok(Program { entry, image })
     }
 }
+
+/// Collect function symbols, and global labels such as `_start`, from the ELF symbol table.
+fn load_symbols(elf: &ElfBytes<LittleEndian>) -> BTreeMap<u32, (u32, String)> {
+    let mut symbols = BTreeMap::new();
+    let Ok(Some((symtab, strtab))) = elf.symbol_table() else {
+        return symbols;
+    };
+    for sym in symtab.iter() {
+        let is_func = sym.st_symtype() == elf::abi::STT_FUNC;
+        let is_label =
+            sym.st_symtype() == elf::abi::STT_NOTYPE && sym.st_bind() == elf::abi::STB_GLOBAL;
+        if (!is_func && !is_label) || sym.st_shndx == elf::abi::SHN_UNDEF {
+            continue;
+        }
+        let (Ok(addr), Ok(size), Ok(name)) = (
+            u32::try_from(sym.st_value),
+            u32::try_from(sym.st_size),
+            strtab.get(sym.st_name as usize),
+        ) else {
+            continue;
+        };
+        if name.is_empty() {
+            continue;
+        }
+        // Prefer functions over labels at the same address.
+        if is_func {
+            symbols.insert(addr, (size, name.into()));
+        } else {
+            symbols.entry(addr).or_insert_with(|| (size, name.into()));
+        }
+    }
+    symbols
+}
+
+const TAG_FILE: u8 = 1;
+const TAG_RISCV_ARCH: u64 = 5;
+
+/// Read the ISA extensions from the `.riscv.attributes` section.
+fn load_extensions(elf: &ElfBytes<LittleEndian>) -> ElfExtensions {
+    let Ok(Some(shdr)) = elf.section_header_by_name(".riscv.attributes") else {
+        return ElfExtensions::default();
+    };
+    let Ok((data, None)) = elf.section_data(&shdr) else {
+        return ElfExtensions::default();
+    };
+    parse_riscv_arch(data)
+        .map(ElfExtensions::from_arch)
+        .unwrap_or_default()
+}
+
+/// Find the `Tag_RISCV_arch` string in the contents of a `.riscv.attributes` section.
+fn parse_riscv_arch(data: &[u8]) -> Option<&str> {
+    let (&format, mut data) = data.split_first()?;
+    if format != b'A' {
+        return None;
+    }
+    while data.len() >= 4 {
+        // Each subsection length includes the length field itself.
+        let len = u32::from_le_bytes(data[..4].try_into().ok()?) as usize;
+        let subsection = data.get(4..len)?;
+        data = &data[len..];
+        let vendor_len = subsection.iter().position(|&b| b == 0)?;
+        if &subsection[..vendor_len] != b"riscv" {
+            continue;
+        }
+        let mut subsection = &subsection[vendor_len + 1..];
+        while subsection.len() >= 5 {
+            let tag = subsection[0];
+            let len = u32::from_le_bytes(subsection[1..5].try_into().ok()?) as usize;
+            let mut attrs = subsection.get(5..len)?;
+            subsection = &subsection[len..];
+            if tag != TAG_FILE {
+                continue;
+            }
+            while !attrs.is_empty() {
+                // Even tags take an integer value, odd tags a NUL-terminated string.
+                let tag = read_uleb128(&mut attrs)?;
+                if tag % 2 == 0 {
+                    read_uleb128(&mut attrs)?;
+                    continue;
+                }
+                let value_len = attrs.iter().position(|&b| b == 0)?;
+                let value = &attrs[..value_len];
+                attrs = &attrs[value_len + 1..];
+                if tag == TAG_RISCV_ARCH {
+                    return core::str::from_utf8(value).ok();
+                }
+            }
+        }
+    }
+    None
+}
+
+fn read_uleb128(data: &mut &[u8]) -> Option<u64> {
+    let mut result = 0;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = data.split_first()?;
+        *data = rest;
+        if shift < 64 {
+            result |= ((byte & 0x7f) as u64) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Some(result);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risc0_zkvm_methods::{ATOMIC_ELF, MULTI_TEST_ELF};
+    use risc0_zkvm_platform::memory::GUEST_MAX_MEM;
+
+    use super::{parse_riscv_arch, ElfExtensions, ElfInfo, Program};
+
+    /// Build a `.riscv.attributes` section with a stack alignment and the given ISA string.
+    fn attributes(arch: &str) -> Vec<u8> {
+        let mut attrs = vec![4, 16, 5];
+        attrs.extend_from_slice(arch.as_bytes());
+        attrs.push(0);
+
+        let mut file = vec![1];
+        file.extend_from_slice(&(attrs.len() as u32 + 5).to_le_bytes());
+        file.extend(attrs);
+
+        let mut subsection = b"riscv\0".to_vec();
+        subsection.extend(file);
+
+        let mut data = vec![b'A'];
+        data.extend_from_slice(&(subsection.len() as u32 + 4).to_le_bytes());
+        data.extend(subsection);
+        data
+    }
+
+    #[test]
+    fn required_extensions() {
+        let rv32im = attributes("rv32i2p1_m2p0_zmmul1p0");
+        let rv32ima = attributes("rv32i2p1_m2p0_a2p1_zmmul1p0");
+        assert_eq!(parse_riscv_arch(&rv32im), Some("rv32i2p1_m2p0_zmmul1p0"));
+
+        let rv32im = ElfExtensions::from_arch(parse_riscv_arch(&rv32im).unwrap());
+        let rv32ima = ElfExtensions::from_arch(parse_riscv_arch(&rv32ima).unwrap());
+        assert_eq!(
+            rv32im,
+            ElfExtensions {
+                m: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            rv32ima,
+            ElfExtensions {
+                m: true,
+                a: true,
+                ..Default::default()
+            }
+        );
+        assert_ne!(rv32im, rv32ima);
+
+        assert_eq!(
+            ElfExtensions::from_arch("rv32gc"),
+            ElfExtensions {
+                m: true,
+                a: true,
+                f: true,
+                d: true,
+                c: true,
+            }
+        );
+        assert_eq!(parse_riscv_arch(b"A"), None);
+
+        // Guests are built for rv32im, unless they enable more target features.
+        let rv32im = ElfInfo::load(MULTI_TEST_ELF).unwrap().required_extensions();
+        let rv32ima = ElfInfo::load(ATOMIC_ELF).unwrap().required_extensions();
+        assert_eq!(
+            rv32im,
+            ElfExtensions {
+                m: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            rv32ima,
+            ElfExtensions {
+                m: true,
+                a: true,
+                ..Default::default()
+            }
+        );
+        assert_ne!(rv32im, rv32ima);
+    }
+
+    #[test]
+    fn symbol_at() {
+        let program = Program::load_elf(MULTI_TEST_ELF, GUEST_MAX_MEM as u32).unwrap();
+        let info = ElfInfo::load(MULTI_TEST_ELF).unwrap();
+        assert_eq!(info.symbol_at(program.entry), Some("_start"));
+        assert_eq!(info.symbol_at(0), None);
+
+        assert_eq!(ElfInfo::default().symbol_at(program.entry), None);
+    }
+}
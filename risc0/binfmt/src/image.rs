@@ -80,6 +80,34 @@ pub struct PageTableInfo {
     zero_page_hash: Digest,
 }
 
+/// Mismatches between two [PageTableInfo]s, as reported by [PageTableInfo::diff].
+///
+/// Each field holds `(self, other)` when the two tables disagree, and `None` otherwise.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PageTableDiff {
+    /// Size of each page, in bytes.
+    pub page_size: Option<(u32, u32)>,
+    /// Starting address for the page table in the memory space.
+    pub page_table_addr: Option<(u32, u32)>,
+    /// Address of the root page.
+    pub root_addr: Option<(u32, u32)>,
+    /// Page index of the root page.
+    pub root_idx: Option<(u32, u32)>,
+    /// Total number of pages covered by the page table.
+    pub num_pages: Option<(u32, u32)>,
+    /// Number of entries in the root page.
+    pub num_root_entries: Option<(u32, u32)>,
+    /// Size in bytes of each layer of the Merkle tree, from the leaves up.
+    pub layers: Option<(Vec<u32>, Vec<u32>)>,
+}
+
+impl PageTableDiff {
+    /// Returns true if the two page tables have the same structure.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
 impl TryFrom<PersistentPageTableInfo> for PageTableInfo {
     type Error = anyhow::Error;
 
@@ -181,6 +209,26 @@ impl PageTableInfo {
     pub fn get_page_entry_addr(&self, page_idx: u32) -> u32 {
         self.page_table_addr + page_idx * DIGEST_BYTES as u32
     }
+
+    /// Compare the structure of this page table against `other`.
+    ///
+    /// This is useful for tracking down why an image restored from a checkpoint has a different
+    /// image ID than the original.
+    pub fn diff(&self, other: &PageTableInfo) -> PageTableDiff {
+        fn field<T: PartialEq + Clone>(lhs: &T, rhs: &T) -> Option<(T, T)> {
+            (lhs != rhs).then(|| (lhs.clone(), rhs.clone()))
+        }
+
+        PageTableDiff {
+            page_size: field(&self.page_size, &other.page_size),
+            page_table_addr: field(&self.page_table_addr, &other.page_table_addr),
+            root_addr: field(&self.root_addr, &other.root_addr),
+            root_idx: field(&self.root_idx, &other.root_idx),
+            num_pages: field(&self.num_pages, &other.num_pages),
+            num_root_entries: field(&self.num_root_entries, &other.num_root_entries),
+            layers: field(&self._layers, &other._layers),
+        }
+    }
 }
 
 impl MemoryImage {
@@ -257,6 +305,39 @@ impl MemoryImage {
         Ok(())
     }
 
+    /// Zero `len` bytes of memory starting at `addr`, allocating any pages
+    /// covering the region that are not yet present. Unlike
+    /// [Self::store_region_in_page], the region may span page boundaries.
+    ///
+    /// Returns an error if the region overflows the address space or extends
+    /// past the last page of the image.
+    ///
+    /// The merkle tree is not updated; call [Self::hash_pages] once all
+    /// changes to the image have been made.
+    pub fn zero_region(&mut self, addr: u32, len: u32) -> Result<()> {
+        let end = addr
+            .checked_add(len)
+            .ok_or_else(|| anyhow!("region {addr:08X}+{len:08X} overflows the address space"))?;
+        if len > 0 {
+            let last_page_idx = self.info.get_page_index(end - 1);
+            ensure!(
+                last_page_idx < self.info.num_pages,
+                "region {addr:08X}..{end:08X} extends past the last page ({} pages)",
+                self.info.num_pages
+            );
+        }
+        let zeros = vec![0_u8; self.info.page_size as usize];
+        let mut addr = addr;
+        while addr < end {
+            let page_start = self.info.get_page_addr(self.info.get_page_index(addr));
+            let page_end = page_start.saturating_add(self.info.page_size);
+            let chunk_len = page_end.min(end) - addr;
+            self.store_region_in_page(addr, &zeros[..chunk_len as usize]);
+            addr += chunk_len;
+        }
+        Ok(())
+    }
+
     /// Calculate and update the image merkle tree within this image.
     pub fn hash_pages(&mut self) {
         self.hash_pages_iter(0..self.info.num_pages)
@@ -366,7 +447,11 @@ mod tests {
     };
     use test_log::test;
 
-    use crate::{elf::Program, image::PageTableInfo, MemoryImage};
+    use crate::{
+        elf::Program,
+        image::{PageTableDiff, PageTableInfo},
+        MemoryImage,
+    };
 
     fn page_table_size(max_mem: u32, page_size: u32) -> u32 {
         PageTableInfo::new(max_mem, page_size)
@@ -391,6 +476,49 @@ mod tests {
         image.check(image.info.root_page_addr).unwrap();
     }
 
+    #[test]
+    fn zero_region() {
+        const PAGE_SIZE: u32 = 1024;
+        let program = Program::load_elf(MULTI_TEST_ELF, GUEST_MAX_MEM as u32).unwrap();
+        let mut image = MemoryImage::new(&program, PAGE_SIZE).unwrap();
+
+        let load = |image: &MemoryImage, addr: u32| {
+            let mut byte = [0];
+            image.load_region_in_page(addr, &mut byte).unwrap();
+            byte[0]
+        };
+
+        // An unaligned region spanning several pages of program text.
+        let start = TEXT_START + 100;
+        let len = PAGE_SIZE * 2 + 7;
+        let before = load(&image, start - 1);
+        let after = load(&image, start + len);
+        assert!((start..start + len).any(|addr| load(&image, addr) != 0));
+
+        image.zero_region(start, len).unwrap();
+        assert!((start..start + len).all(|addr| load(&image, addr) == 0));
+        assert_eq!(load(&image, start - 1), before);
+        assert_eq!(load(&image, start + len), after);
+
+        // Pages that were never touched are allocated.
+        let untouched = STACK_TOP - 16 * PAGE_SIZE;
+        let page_idx = image.info.get_page_index(untouched);
+        assert!(!image.pages.contains_key(&page_idx));
+        image.zero_region(untouched, 1).unwrap();
+        assert_eq!(image.pages[&page_idx], vec![0; PAGE_SIZE as usize]);
+
+        // Out-of-range regions are rejected without touching the image.
+        let root_page_addr = image.info.root_page_addr;
+        assert!(image.zero_region(u32::MAX, 2).is_err());
+        assert!(image.zero_region(root_page_addr, 1).is_err());
+        assert!(image.zero_region(root_page_addr - 1, 2).is_err());
+        image.zero_region(root_page_addr, 0).unwrap();
+
+        image.hash_pages();
+        image.check(start).unwrap();
+        image.check(untouched).unwrap();
+    }
+
     #[test]
     fn page_table_info() {
         const PAGE_SIZE_1K: u32 = 1024;
@@ -405,6 +533,36 @@ mod tests {
         assert_eq!(info.root_idx, 219862);
     }
 
+    #[test]
+    fn page_table_info_diff() {
+        let info = PageTableInfo::new(PAGE_TABLE.start() as u32, 1024).unwrap();
+        assert!(info.diff(&info.clone()).is_empty());
+
+        let mut altered = info.clone();
+        altered.root_idx += 1;
+        altered.num_root_entries -= 1;
+        altered._layers.pop();
+        let diff = info.diff(&altered);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff,
+            PageTableDiff {
+                root_idx: Some((219862, 219863)),
+                num_root_entries: Some((22, 21)),
+                layers: Some((
+                    vec![6815744, 212992, 6656, 192],
+                    vec![6815744, 212992, 6656]
+                )),
+                ..Default::default()
+            }
+        );
+
+        let info_4k = PageTableInfo::new(PAGE_TABLE.start() as u32, 4096).unwrap();
+        let diff = info.diff(&info_4k);
+        assert_eq!(diff.page_size, Some((1024, 4096)));
+        assert_eq!(diff.page_table_addr, None);
+    }
+
     #[test]
     fn page_size_1k() {
         const PAGE_SIZE_1K: u32 = 1024;
@@ -27,9 +27,9 @@ mod image;
 mod sys_state;
 
 #[cfg(not(target_os = "zkvm"))]
-pub use self::image::{MemoryImage, PageTableInfo};
+pub use self::image::{MemoryImage, PageTableDiff, PageTableInfo};
 pub use crate::{
-    elf::Program,
+    elf::{ElfExtensions, ElfInfo, Program},
     exit_code::{ExitCode, InvalidExitCodeError},
     hash::{tagged_iter, tagged_list, tagged_list_cons, tagged_struct, Digestible},
     sys_state::{read_sha_halfs, write_sha_halfs, DecodeError, SystemState},
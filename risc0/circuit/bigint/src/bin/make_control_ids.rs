@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use anyhow::Result;
-use risc0_circuit_bigint::{zkr::get_zkr, BigIntProgram, BIGINT_PO2};
+use risc0_circuit_bigint::{
+    zkr::{control_root_for, get_zkr},
+    BigIntProgram, BIGINT_PO2,
+};
 use risc0_zkp::core::{digest::Digest, hash::poseidon2::Poseidon2HashSuite};
-use risc0_zkvm::recursion::MerkleGroup;
 
 fn compute_control_id(prog: &BigIntProgram) -> Result<Digest> {
     let program = get_zkr(&format!("{}.zkr", prog.name), BIGINT_PO2)?;
@@ -24,11 +26,7 @@ fn compute_control_id(prog: &BigIntProgram) -> Result<Digest> {
 }
 
 fn compute_control_root(prog: &BigIntProgram) -> Result<Digest> {
-    let control_id = compute_control_id(prog)?;
-    let hash_suite = Poseidon2HashSuite::new_suite();
-    let hashfn = hash_suite.hashfn.as_ref();
-    let group = MerkleGroup::new(vec![control_id])?;
-    Ok(group.calc_root(hashfn))
+    control_root_for(&[compute_control_id(prog)?])
 }
 
 pub fn main() -> Result<()> {
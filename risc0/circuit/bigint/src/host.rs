@@ -15,11 +15,10 @@
 use std::{borrow::Borrow, collections::VecDeque};
 
 use anyhow::{bail, Result};
-use risc0_binfmt::{read_sha_halfs, Digestible};
+use risc0_binfmt::read_sha_halfs;
 use risc0_circuit_recursion::{
     prove::RecursionReceipt,
     prove::{Program, Prover},
-    CHECKED_COEFFS_PER_POLY,
 };
 use risc0_core::field::Elem;
 use risc0_zkp::{
@@ -30,77 +29,20 @@ use risc0_zkp::{
 };
 use tracing::{debug, trace};
 
-use crate::{
-    byte_poly, claim_list_digest, pad_claim_list, BigIntClaim, BigIntContext, BigIntProgram,
-};
+use crate::{claim_list_digest, zkr_input, BigIntClaim, BigIntProgram};
 
 pub fn prove<S: Sha256>(
     claims: &[impl Borrow<BigIntClaim>],
     prog: &BigIntProgram,
     zkr: Program,
 ) -> Result<RecursionReceipt> {
-    let mut prover = Prover::new(zkr, "poseidon2");
-    prover.add_input(prog.control_root.as_words());
-
-    let hash_suite = Poseidon2HashSuite::new_suite();
-    let mut rng = hash_suite.rng.new_rng();
-
-    for claim in pad_claim_list(prog, claims)? {
-        debug!("claim: {claim:?}");
-        let mut ctx = BigIntContext {
-            in_values: claim
-                .public_witness
-                .iter()
-                .map(Vec::as_slice)
-                .map(byte_poly::to_biguint)
-                .collect(),
-            ..Default::default()
-        };
-
-        let claim_digest = claim.digest::<S>();
-        trace!("claim_digest: {claim_digest:?}");
-
-        (prog.unconstrained_eval_fn)(&mut ctx)?;
-
-        let mut all_coeffs: Vec<u32> = Vec::new();
-        for witness in ctx
-            .constant_witness
-            .iter()
-            .chain(ctx.public_witness.iter())
-            .chain(ctx.private_witness.iter())
-        {
-            for chunk in witness.chunks(CHECKED_COEFFS_PER_POLY) {
-                let mut bytes: Vec<u8> = chunk
-                    .iter()
-                    .map(|b| u8::try_from(*b).expect("Byte out of range in witness coeffs"))
-                    .collect();
-                while bytes.len() < CHECKED_COEFFS_PER_POLY {
-                    bytes.push(0);
-                }
-
-                for word in bytes.chunks(4) {
-                    all_coeffs.push(u32::from_le_bytes(
-                        word.try_into().expect("Partial word present in witness?"),
-                    ));
-                }
-            }
-        }
-
-        let public_digest = byte_poly::compute_digest(&*hash_suite.hashfn, &ctx.public_witness, 1);
-        let private_digest =
-            byte_poly::compute_digest(&*hash_suite.hashfn, &ctx.private_witness, 3);
-        let folded = hash_suite.hashfn.hash_pair(&public_digest, &private_digest);
-        trace!("folded: {folded}");
-
-        // Calculate the evaluation point Z
-        rng.mix(&folded);
-        let z = rng.random_ext_elem();
+    let claim_digest = claim_list_digest::<S>(prog, claims)?;
+    trace!("claim_digest: {claim_digest:?}");
 
-        trace!("evaluation point: {z:?}");
+    let input = zkr_input(prog, claims)?;
 
-        prover.add_input(&z.to_u32_words());
-        prover.add_input(&all_coeffs);
-    }
+    let mut prover = Prover::new(zkr, "poseidon2");
+    prover.add_input(&input);
 
     trace!("Running prover");
     let receipt = prover.run()?;
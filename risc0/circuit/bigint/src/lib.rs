@@ -58,7 +58,13 @@ use byte_poly::BITS_PER_COEFF;
 use num_bigint::BigUint;
 use risc0_binfmt::{tagged_list, Digestible};
 use risc0_circuit_recursion::CHECKED_COEFFS_PER_POLY;
-use risc0_zkp::core::{digest::Digest, hash::sha::Sha256};
+use risc0_zkp::{
+    core::{
+        digest::Digest,
+        hash::{poseidon2::Poseidon2HashSuite, sha::Sha256},
+    },
+    field::Elem,
+};
 
 pub use generated::PROGRAMS;
 
@@ -76,6 +82,34 @@ pub struct BigIntContext {
     pub private_witness: Vec<Vec<i32>>,
 }
 
+impl BigIntContext {
+    /// Evaluate `prog` on [Self::in_values], replacing the constant, public, and private
+    /// witnesses with the result.
+    ///
+    /// Every witness coefficient is checked to be a single byte, the same reduced form that
+    /// [byte_poly::from_biguint] produces. The public witness can therefore be used directly as
+    /// a [BigIntClaim], without a round-trip through the guest.
+    pub fn reduce_witnesses(&mut self, prog: &BigIntProgram) -> Result<()> {
+        self.constant_witness.clear();
+        self.public_witness.clear();
+        self.private_witness.clear();
+        (prog.unconstrained_eval_fn)(self)?;
+
+        for witness in self
+            .constant_witness
+            .iter()
+            .chain(self.public_witness.iter())
+            .chain(self.private_witness.iter())
+        {
+            ensure!(
+                witness.iter().all(|coeff| u8::try_from(*coeff).is_ok()),
+                "Byte out of range in witness coeffs"
+            );
+        }
+        Ok(())
+    }
+}
+
 /// Information about a big integer included in a bigint witness.
 #[derive(Debug)]
 pub struct WitnessInfo {
@@ -116,6 +150,29 @@ impl BigIntClaim {
         BigIntClaim { public_witness }
     }
 
+    /// Constructs a claim from an already-encoded public witness, checking that
+    /// each polynomial has the number of coefficients `prog` expects.
+    pub fn new_checked(public_witness: Vec<Vec<i32>>, prog: &BigIntProgram) -> Result<Self> {
+        ensure!(
+            public_witness.len() == prog.witness_info.len(),
+            "{}: expected {} public witness values, got {}",
+            prog.name,
+            prog.witness_info.len(),
+            public_witness.len()
+        );
+        for (poly, wit_info) in public_witness.iter().zip(prog.witness_info.iter()) {
+            ensure!(
+                poly.len() == wit_info.coeffs(),
+                "{}: witness with label {} expected {} coefficients, got {}",
+                prog.name,
+                wit_info.label,
+                wit_info.coeffs(),
+                poly.len()
+            );
+        }
+        Ok(BigIntClaim { public_witness })
+    }
+
     pub fn from_biguints(
         prog_info: &BigIntProgram,
         biguints: &[impl ToOwned<Owned = BigUint>],
@@ -189,3 +246,73 @@ pub(crate) fn pad_claim_list<'a>(
     );
     Ok(claim_refs)
 }
+
+/// Assembles the input read by the ZKR for `prog` when verifying `claims`.
+///
+/// The input is the control root of `prog`, followed by the evaluation point Z
+/// and the packed witness coefficients of each claim, with the claim list
+/// padded out to `prog.iters` claims.
+pub(crate) fn zkr_input(
+    prog: &BigIntProgram,
+    claims: &[impl Borrow<BigIntClaim>],
+) -> Result<Vec<u32>> {
+    let mut input: Vec<u32> = Vec::new();
+    input.extend(prog.control_root.as_words());
+
+    let hash_suite = Poseidon2HashSuite::new_suite();
+    let mut rng = hash_suite.rng.new_rng();
+
+    for claim in pad_claim_list(prog, claims)? {
+        let mut ctx = BigIntContext {
+            in_values: claim
+                .public_witness
+                .iter()
+                .map(Vec::as_slice)
+                .map(byte_poly::to_biguint)
+                .collect(),
+            ..Default::default()
+        };
+        ctx.reduce_witnesses(prog)?;
+
+        let mut all_coeffs: Vec<u32> = Vec::new();
+        for witness in ctx
+            .constant_witness
+            .iter()
+            .chain(ctx.public_witness.iter())
+            .chain(ctx.private_witness.iter())
+        {
+            for chunk in witness.chunks(CHECKED_COEFFS_PER_POLY) {
+                let mut bytes: Vec<u8> = chunk
+                    .iter()
+                    .map(|b| u8::try_from(*b).expect("Byte out of range in witness coeffs"))
+                    .collect();
+                while bytes.len() < CHECKED_COEFFS_PER_POLY {
+                    bytes.push(0);
+                }
+
+                for word in bytes.chunks(4) {
+                    all_coeffs.push(u32::from_le_bytes(
+                        word.try_into().expect("Partial word present in witness?"),
+                    ));
+                }
+            }
+        }
+
+        let public_digest = byte_poly::compute_digest(&*hash_suite.hashfn, &ctx.public_witness, 1);
+        let private_digest =
+            byte_poly::compute_digest(&*hash_suite.hashfn, &ctx.private_witness, 3);
+        let folded = hash_suite.hashfn.hash_pair(&public_digest, &private_digest);
+        tracing::trace!("folded: {folded}");
+
+        // Calculate the evaluation point Z
+        rng.mix(&folded);
+        let z = rng.random_ext_elem();
+
+        tracing::trace!("evaluation point: {z:?}");
+
+        input.extend(z.to_u32_words());
+        input.extend(all_coeffs);
+    }
+
+    Ok(input)
+}
@@ -14,24 +14,26 @@
 
 use std::borrow::Borrow;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use num_bigint::BigUint;
 use risc0_circuit_bigint_test_methods::{RSA_ELF, RSA_ID};
-use risc0_zkp::core::hash::sha;
+use risc0_circuit_recursion::CHECKED_COEFFS_PER_POLY;
+use risc0_zkp::core::{digest::DIGEST_WORDS, hash::sha};
 use risc0_zkp::field::{
     baby_bear::{BabyBearElem, BabyBearExtElem},
     Elem, ExtElem,
 };
 use risc0_zkvm::{get_prover_server, ExecutorEnv, ProverOpts};
+use risc0_zkvm_platform::WORD_SIZE;
 use test_log::test;
 
 use crate::{
-    prove,
+    claim_list_digest, prove,
     rsa::RSA_256_X2,
     test_harness::{from_hex, test_witgen, test_zkr, witness_test_data},
     verify,
     zkr::{get_zkr, register_zkrs},
-    BigIntContext, BIGINT_PO2,
+    zkr_input, BigIntClaim, BigIntContext, BigIntProgram, BIGINT_PO2,
 };
 
 // "golden" values are the values from running the C++ version:
@@ -211,8 +213,105 @@ fn prove_and_verify_rsa() {
     verify::<sha::Impl>(&crate::rsa::RSA_256_X2, &[&claim], &receipt).unwrap();
 }
 
+/// Stands in for the ZKR on the host by checking the shape of its `input`
+/// without proving anything.
+///
+/// The input must start with the control root of `prog`, followed by one
+/// equally-sized entry per claim, each an evaluation point Z and whole
+/// polynomials of packed witness coefficients.
+fn check_zkr_input(prog: &BigIntProgram, input: &[u32]) -> Result<()> {
+    const Z_WORDS: usize = BabyBearExtElem::EXT_SIZE;
+    const POLY_WORDS: usize = CHECKED_COEFFS_PER_POLY / WORD_SIZE;
+
+    let Some(claims_input) = input.strip_prefix(prog.control_root.as_words()) else {
+        bail!(
+            "{}: ZKR input does not start with the control root",
+            prog.name
+        );
+    };
+    if prog.iters == 0 || claims_input.len() % prog.iters != 0 {
+        bail!(
+            "{}: ZKR input of {} words does not split into {} claims",
+            prog.name,
+            claims_input.len(),
+            prog.iters
+        );
+    }
+    let claim_words = claims_input.len() / prog.iters;
+    if claim_words < Z_WORDS || (claim_words - Z_WORDS) % POLY_WORDS != 0 {
+        bail!(
+            "{}: ZKR input has {claim_words} words per claim, which is not an evaluation point \
+             followed by whole polynomials",
+            prog.name
+        );
+    }
+    Ok(())
+}
+
+// Checks the layout of the input the guest passes to sys_prove_zkr.
+#[test]
+fn zkr_input_shape() {
+    let [n, s, m] = golden_values().try_into().unwrap();
+    let claim = crate::rsa::claim(&RSA_256_X2, n, s, m);
+    let input = zkr_input(&RSA_256_X2, &[&claim]).unwrap();
+    check_zkr_input(&RSA_256_X2, &input).unwrap();
+
+    let mut ctx = BigIntContext {
+        in_values: golden_values(),
+        ..Default::default()
+    };
+    ctx.reduce_witnesses(&RSA_256_X2).unwrap();
+    let coeff_words: usize = ctx
+        .constant_witness
+        .iter()
+        .chain(ctx.public_witness.iter())
+        .chain(ctx.private_witness.iter())
+        .map(|witness| {
+            witness.len().div_ceil(CHECKED_COEFFS_PER_POLY) * CHECKED_COEFFS_PER_POLY / 4
+        })
+        .sum();
+    let z_words = BabyBearExtElem::EXT_SIZE;
+    assert_eq!(
+        input.len(),
+        DIGEST_WORDS + RSA_256_X2.iters * (z_words + coeff_words)
+    );
+    assert_eq!(&input[..DIGEST_WORDS], RSA_256_X2.control_root.as_words());
+
+    // A truncated input no longer splits evenly into claims.
+    check_zkr_input(&RSA_256_X2, &input[..input.len() - 1]).unwrap_err();
+}
+
+// Builds a claim on the host from the program's own witness generation.
+#[test]
+fn reduce_witnesses() {
+    let [n, s, m] = golden_values().try_into().unwrap();
+    let claim = crate::rsa::claim(&RSA_256_X2, n, s, m);
+
+    let mut ctx = BigIntContext {
+        in_values: golden_values(),
+        ..Default::default()
+    };
+    ctx.reduce_witnesses(&RSA_256_X2).unwrap();
+    assert_eq!(ctx.public_witness, claim.public_witness);
+
+    // Evaluating again replaces, rather than appends to, the witnesses.
+    let private_len = ctx.private_witness.len();
+    ctx.reduce_witnesses(&RSA_256_X2).unwrap();
+    assert_eq!(ctx.public_witness, claim.public_witness);
+    assert_eq!(ctx.private_witness.len(), private_len);
+
+    let claim = BigIntClaim::new(ctx.public_witness);
+    let input = zkr_input(&RSA_256_X2, &[&claim]).unwrap();
+    check_zkr_input(&RSA_256_X2, &input).unwrap();
+}
+
 fn run_guest_compose(claims: &[impl Borrow<[BigUint; 3]>]) -> Result<()> {
     let claims: Vec<[BigUint; 3]> = claims.iter().map(Borrow::borrow).cloned().collect();
+    let bigint_claims: Vec<_> = claims
+        .iter()
+        .cloned()
+        .map(|[n, s, m]| crate::rsa::claim(&RSA_256_X2, n, s, m))
+        .collect();
     let env = ExecutorEnv::builder()
         // Send a & b to the guest
         .write(&claims)?
@@ -233,6 +332,10 @@ fn run_guest_compose(claims: &[impl Borrow<[BigUint; 3]>]) -> Result<()> {
         .unwrap()
         .assumption_receipts
         .is_empty());
+    assert_eq!(
+        receipt.assumption_digests()?,
+        vec![claim_list_digest::<sha::Impl>(&RSA_256_X2, &bigint_claims)?]
+    );
 
     // Make sure the receipt verifies OK
     receipt.verify(RSA_ID)?;
@@ -283,3 +386,31 @@ fn guest_compose_corrupted() {
         ));
     }
 }
+
+#[test]
+fn claim_new_checked() {
+    let claim = BigIntClaim::from_biguints(&RSA_256_X2, &golden_values());
+    let checked = BigIntClaim::new_checked(claim.public_witness.clone(), &RSA_256_X2).unwrap();
+    assert_eq!(checked.public_witness, claim.public_witness);
+}
+
+#[test]
+fn claim_new_checked_short_poly() {
+    let mut public_witness =
+        BigIntClaim::from_biguints(&RSA_256_X2, &golden_values()).public_witness;
+    public_witness[1].pop();
+    let err = BigIntClaim::new_checked(public_witness, &RSA_256_X2)
+        .err()
+        .unwrap()
+        .to_string();
+    let wit_info = &RSA_256_X2.witness_info[1];
+    assert!(err.contains(&format!("label {}", wit_info.label)), "{err}");
+    assert!(
+        err.contains(&format!(
+            "expected {} coefficients, got {}",
+            wit_info.coeffs(),
+            wit_info.coeffs() - 1
+        )),
+        "{err}"
+    );
+}
@@ -17,7 +17,11 @@ use std::io::{Cursor, Read};
 use anyhow::{ensure, Context, Result};
 use risc0_circuit_recursion::{prove::Program, CIRCUIT, REGISTER_GROUP_CODE};
 use risc0_core::field::baby_bear::BabyBearElem;
-use risc0_zkp::adapter::TapsProvider;
+use risc0_zkp::{
+    adapter::TapsProvider,
+    core::{digest::Digest, hash::poseidon2::Poseidon2HashSuite},
+};
+use risc0_zkvm::recursion::MerkleGroup;
 use zip::ZipArchive;
 
 use crate::BIGINT_PO2;
@@ -81,6 +85,16 @@ pub fn get_zkr(name: &str, po2: usize) -> Result<Program> {
     })
 }
 
+/// Compute the Poseidon2 control root of a Merkle tree containing the given control IDs.
+///
+/// Each bigint program is verified against a tree with only its own control ID, so its
+/// `control_root` is `control_root_for(&[control_id])`.
+pub fn control_root_for(ids: &[Digest]) -> Result<Digest> {
+    let hash_suite = Poseidon2HashSuite::new_suite();
+    let group = MerkleGroup::new(ids.to_vec())?;
+    Ok(group.calc_root(hash_suite.hashfn.as_ref()))
+}
+
 static REGISTER_ZKRS: std::sync::Once = std::sync::Once::new();
 
 pub fn register_zkrs() {
@@ -99,9 +113,9 @@ mod tests {
     use anyhow::{Context, Result};
     use risc0_zkp::core::digest::Digest;
     use risc0_zkp::core::hash::poseidon2::Poseidon2HashSuite;
-    use risc0_zkvm::recursion::MerkleGroup;
 
-    use crate::{BigIntProgram, BIGINT_PO2};
+    use super::control_root_for;
+    use crate::{rsa::RSA_256_X1, BigIntProgram, BIGINT_PO2};
 
     fn compute_control_id(prog: &BigIntProgram) -> Result<Digest> {
         let program = crate::zkr::get_zkr(&format!("{}.zkr", prog.name), BIGINT_PO2)?;
@@ -110,11 +124,7 @@ mod tests {
     }
 
     fn compute_control_root(prog: &BigIntProgram) -> Result<Digest> {
-        let control_id = compute_control_id(prog)?;
-        let hash_suite = Poseidon2HashSuite::new_suite();
-        let hashfn = hash_suite.hashfn.as_ref();
-        let group = MerkleGroup::new(vec![control_id])?;
-        Ok(group.calc_root(hashfn))
+        control_root_for(&[compute_control_id(prog)?])
     }
 
     fn verify_control_id(prog: &BigIntProgram) -> Result<()> {
@@ -153,4 +163,13 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn single_element_control_root() -> Result<()> {
+        assert_eq!(
+            control_root_for(&[RSA_256_X1.control_id])?,
+            RSA_256_X1.control_root
+        );
+        Ok(())
+    }
 }
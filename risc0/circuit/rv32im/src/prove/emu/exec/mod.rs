@@ -57,6 +57,18 @@ use crate::{
 
 pub const DEFAULT_SEGMENT_LIMIT_PO2: usize = 20;
 
+/// The default limit on the number of words a software syscall may return to
+/// the guest: 16 MiB. The host allocates the reply before it runs the handler,
+/// so this bounds the host memory a single guest request can claim.
+pub const DEFAULT_MAX_SYSCALL_RETURN_WORDS: usize = (16 << 20) / WORD_SIZE;
+
+/// The default limit on the number of blocks a single SHA-256 ecall may
+/// compress: the most blocks whose cycles fit in a segment of
+/// [MAX_CYCLES_PO2]. All cycles of an ecall are charged to a single segment, so
+/// a larger ecall could never be proven. Guests hashing large inputs should
+/// make multiple ecalls, as `sys_sha_buffer` does.
+pub const DEFAULT_MAX_SHA_BLOCKS: u32 = ((1 << MAX_CYCLES_PO2) / sha_cycles(1)) as u32;
+
 /// A host-side implementation of a system call.
 pub trait Syscall {
     /// Invokes the system call.
@@ -148,6 +160,8 @@ pub struct Executor<'a, 'b, S: Syscall> {
     pending: PendingState,
     trace: Vec<Rc<RefCell<dyn TraceCallback + 'b>>>,
     cycles: SessionCycles,
+    max_syscall_return_words: usize,
+    max_sha_blocks: u32,
 }
 
 impl PendingState {
@@ -188,9 +202,26 @@ impl<'a, 'b, S: Syscall> Executor<'a, 'b, S> {
             },
             trace,
             cycles: SessionCycles::default(),
+            max_syscall_return_words: DEFAULT_MAX_SYSCALL_RETURN_WORDS,
+            max_sha_blocks: DEFAULT_MAX_SHA_BLOCKS,
         }
     }
 
+    /// Set the maximum number of words a software syscall may return to the
+    /// guest. Requests for more than this are rejected before any host memory
+    /// is allocated for them.
+    pub fn with_max_syscall_return_words(mut self, max_words: usize) -> Self {
+        self.max_syscall_return_words = max_words;
+        self
+    }
+
+    /// Set the maximum number of blocks a single SHA-256 ecall may compress.
+    /// Larger requests are rejected before any blocks are processed.
+    pub fn with_max_sha_blocks(mut self, max_blocks: u32) -> Self {
+        self.max_sha_blocks = max_blocks;
+        self
+    }
+
     pub fn run<F: FnMut(Segment) -> Result<()>>(
         &mut self,
         segment_po2: usize,
@@ -355,6 +386,10 @@ impl<'a, 'b, S: Syscall> Executor<'a, 'b, S> {
     fn ecall_halt(&mut self) -> Result<bool> {
         let a0 = self.load_register(REG_A0)?;
         let output_ptr = self.load_guest_addr_from_register(REG_A1)?;
+        ensure!(
+            output_ptr.is_aligned(),
+            "ecall_halt: output_ptr {output_ptr:?} is not word aligned"
+        );
         let output: [u8; DIGEST_BYTES] = self.load_array_from_guest(output_ptr)?;
 
         let halt_type = a0 & 0xff;
@@ -393,6 +428,12 @@ impl<'a, 'b, S: Syscall> Executor<'a, 'b, S> {
         if into_guest_len > 0 && !is_guest_memory(into_guest_ptr.0) {
             bail!("{into_guest_ptr:?} is an invalid guest address");
         }
+        if into_guest_len > self.max_syscall_return_words {
+            bail!(
+                "ecall_software: into_guest length ({into_guest_len} words) exceeds limit ({} words)",
+                self.max_syscall_return_words
+            );
+        }
         let name_ptr = self.load_guest_addr_from_register(REG_A2)?;
         let syscall_name = self.peek_string(name_ptr)?;
         let name_end = name_ptr + syscall_name.len();
@@ -443,6 +484,12 @@ impl<'a, 'b, S: Syscall> Executor<'a, 'b, S> {
         let state_out_ptr = self.load_guest_addr_from_register(REG_A0)?;
         let state_in_ptr = self.load_guest_addr_from_register(REG_A1)?;
         let count = self.load_register(REG_A4)?;
+        if count > self.max_sha_blocks {
+            bail!(
+                "ecall_sha: block count ({count}) exceeds limit ({})",
+                self.max_sha_blocks
+            );
+        }
 
         let state_in: [u8; DIGEST_BYTES] = self.load_array_from_guest(state_in_ptr)?;
         let mut state: [u32; DIGEST_WORDS] = bytemuck::cast_slice(&state_in).try_into().unwrap();
@@ -453,6 +500,14 @@ impl<'a, 'b, S: Syscall> Executor<'a, 'b, S> {
         if count > 0 {
             let mut block1_ptr = self.load_guest_addr_from_register(REG_A2)?;
             let mut block2_ptr = self.load_guest_addr_from_register(REG_A3)?;
+            ensure!(
+                block1_ptr.is_aligned(),
+                "ecall_sha: block1_ptr {block1_ptr:?} is not word aligned"
+            );
+            ensure!(
+                block2_ptr.is_aligned(),
+                "ecall_sha: block2_ptr {block2_ptr:?} is not word aligned"
+            );
 
             // tracing::debug!("ecall_sha: start state: {state:08x?}");
             let mut block = [0u32; BLOCK_WORDS];
@@ -129,3 +129,114 @@ fn system_split() {
         segments[0].post_state.digest::<ShaImpl>()
     );
 }
+
+#[test]
+fn oversized_syscall() {
+    let program = testutil::oversized_syscall();
+    let image = MemoryImage::new(&program, PAGE_SIZE as u32).unwrap();
+
+    let err = super::execute(
+        image,
+        DEFAULT_SEGMENT_LIMIT_PO2,
+        DEFAULT_SESSION_LIMIT,
+        &BasicSyscall::default(),
+        None,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("exceeds limit"));
+}
+
+#[test]
+fn oversized_sha() {
+    let program = testutil::oversized_sha();
+    let image = MemoryImage::new(&program, PAGE_SIZE as u32).unwrap();
+
+    let err = super::execute(
+        image,
+        DEFAULT_SEGMENT_LIMIT_PO2,
+        DEFAULT_SESSION_LIMIT,
+        &BasicSyscall::default(),
+        None,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("block count"), "{err}");
+}
+
+#[test]
+fn misaligned_sha() {
+    let program = testutil::misaligned_sha();
+    let image = MemoryImage::new(&program, PAGE_SIZE as u32).unwrap();
+
+    let err = super::execute(
+        image,
+        DEFAULT_SEGMENT_LIMIT_PO2,
+        DEFAULT_SESSION_LIMIT,
+        &BasicSyscall::default(),
+        None,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("not word aligned"));
+}
+
+#[test]
+fn bogus_halt_ptr() {
+    for (program, msg) in [
+        (testutil::invalid_halt_ptr(), "invalid guest address"),
+        (testutil::misaligned_halt_ptr(), "not word aligned"),
+    ] {
+        let image = MemoryImage::new(&program, PAGE_SIZE as u32).unwrap();
+
+        let err = super::execute(
+            image,
+            DEFAULT_SEGMENT_LIMIT_PO2,
+            DEFAULT_SESSION_LIMIT,
+            &BasicSyscall::default(),
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains(msg), "{err}");
+    }
+}
+
+#[test]
+fn misaligned_store() {
+    for program in [testutil::misaligned_sh(), testutil::misaligned_sw()] {
+        let image = MemoryImage::new(&program, PAGE_SIZE as u32).unwrap();
+
+        let err = super::execute(
+            image,
+            DEFAULT_SEGMENT_LIMIT_PO2,
+            DEFAULT_SESSION_LIMIT,
+            &BasicSyscall::default(),
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("StoreAddressMisaligned"));
+    }
+}
+
+#[test]
+fn ebreak() {
+    let program = testutil::ebreak();
+    let image = MemoryImage::new(&program, PAGE_SIZE as u32).unwrap();
+
+    // With t0 set up as for a software ecall, an ebreak must still trap rather
+    // than be dispatched as a syscall.
+    let syscall = BasicSyscall::default();
+    let err = super::execute(
+        image,
+        DEFAULT_SEGMENT_LIMIT_PO2,
+        DEFAULT_SESSION_LIMIT,
+        &syscall,
+        None,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("Breakpoint"));
+    assert!(syscall.state().syscall.is_empty());
+}
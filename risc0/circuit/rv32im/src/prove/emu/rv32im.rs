@@ -283,6 +283,21 @@ const RV32IM_ISA: InstructionTable = [
     insn(InsnKind::MRET, InsnCategory::System, 0x73, 0x0, 0x18, 1),
 ];
 
+const SUPPORTED_INSTRUCTIONS: &[&str] = &[
+    "add", "sub", "xor", "or", "and", "sll", "srl", "sra", "slt", "sltu", "addi", "xori", "ori",
+    "andi", "slli", "srli", "srai", "slti", "sltiu", "beq", "bne", "blt", "bge", "bltu", "bgeu",
+    "jal", "jalr", "lui", "auipc", "mul", "mulh", "mulhsu", "mulhu", "div", "divu", "rem", "remu",
+    "lb", "lh", "lw", "lbu", "lhu", "sb", "sh", "sw", "ecall", "ebreak", "mret",
+];
+
+/// Returns the mnemonics of the RISC-V instructions the emulator implements.
+///
+/// This is the RV32IM base and multiply extension, plus `ecall`, `ebreak` and
+/// `mret`. No other extensions (such as A, C, or Zicsr) are supported.
+pub fn supported_instructions() -> &'static [&'static str] {
+    SUPPORTED_INSTRUCTIONS
+}
+
 // RISC-V instruction are determined by 3 parts:
 // - Opcode: 7 bits
 // - Func3: 3 bits
@@ -625,3 +640,29 @@ impl Emulator {
 fn sign_extend_u32(x: u32) -> i64 {
     (x as i32) as i64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        supported_instructions, DecodedInstruction, FastDecodeTable, InsnKind, RV32IM_ISA,
+    };
+
+    #[test]
+    fn supported_instructions_match_isa() {
+        let table = FastDecodeTable::new();
+        let mut mnemonics = Vec::new();
+        for insn in RV32IM_ISA.iter().skip(1) {
+            // Don't-care fields are stored as -1; encode them as 0.
+            let func3 = (insn.func3 as i32).max(0) as u32;
+            let func7 = (insn.func7 as i32).max(0) as u32;
+            let word = (func7 << 25) | (func3 << 12) | insn.opcode;
+            assert_eq!(table.lookup(&DecodedInstruction::new(word)).kind, insn.kind);
+
+            match insn.kind {
+                InsnKind::EANY => mnemonics.extend(["ecall".to_string(), "ebreak".to_string()]),
+                kind => mnemonics.push(format!("{kind:?}").to_lowercase()),
+            }
+        }
+        assert_eq!(supported_instructions(), mnemonics);
+    }
+}
@@ -93,6 +93,103 @@ pub fn simple_loop() -> Program {
     )
 }
 
+pub fn oversized_syscall() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00004537, // lui a0, 0x4
+            0x800005b7, // lui a1, 0x80000
+            0x00200293, // li t0, 2
+            0x00000073, // ecall(software)
+        ],
+    )
+}
+
+pub fn oversized_sha() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00004537, // lui a0, 0x4
+            0x000045b7, // lui a1, 0x4
+            0x00050613, // mv a2, a0
+            0x00050693, // mv a3, a0
+            0x10000737, // lui a4, 0x10000
+            0x00300293, // li t0, 3
+            0x00000073, // ecall(sha)
+        ],
+    )
+}
+
+pub fn misaligned_sha() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00004537, // lui a0, 0x4
+            0x000045b7, // lui a1, 0x4
+            0x00150613, // addi a2, a0, 1
+            0x00050693, // mv a3, a0
+            0x00100713, // li a4, 1
+            0x00300293, // li t0, 3
+            0x00000073, // ecall(sha)
+        ],
+    )
+}
+
+pub fn invalid_halt_ptr() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00000513, // li a0, 0
+            0x01000593, // li a1, 0x10
+            0x00000293, // li t0, 0
+            0x00000073, // ecall(halt)
+        ],
+    )
+}
+
+pub fn misaligned_halt_ptr() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00000513, // li a0, 0
+            0x000045b7, // lui a1, 0x4
+            0x00158593, // addi a1, a1, 1
+            0x00000293, // li t0, 0
+            0x00000073, // ecall(halt)
+        ],
+    )
+}
+
+pub fn misaligned_sh() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00004537, // lui a0, 0x4
+            0x00a510a3, // sh a0, 1(a0)
+        ],
+    )
+}
+
+pub fn misaligned_sw() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00004537, // lui a0, 0x4
+            0x00a52123, // sw a0, 2(a0)
+        ],
+    )
+}
+
+pub fn ebreak() -> Program {
+    program_from_instructions(
+        0x4000,
+        [
+            0x00200293, // li t0, 2
+            0x00100073, // ebreak
+        ],
+    )
+}
+
 pub fn large_text() -> Program {
     let iter = (0..2500).map(|_| {
         0x1234b137 // lui x2, 0x1234b000
@@ -71,6 +71,9 @@ pub enum VerificationError {
     UnresolvedAssumption {
         digest: Digest,
     },
+    ControlIdNotAllowed {
+        control_id: Digest,
+    },
 }
 
 impl fmt::Debug for VerificationError {
@@ -115,6 +118,9 @@ impl fmt::Display for VerificationError {
             VerificationError::UnresolvedAssumption { digest } => {
                 write!(f, "receipt contains an unresolved assumption: {digest}")
             }
+            VerificationError::ControlIdNotAllowed { control_id } => {
+                write!(f, "control_id is not in the allowed set: {control_id}")
+            }
         }
     }
 }
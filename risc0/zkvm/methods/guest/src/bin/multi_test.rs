@@ -113,6 +113,9 @@ fn main() {
         MultiTestSpec::Halt(exit_code) => {
             env::exit(exit_code);
         }
+        MultiTestSpec::Fail { exit_code, msg } => {
+            env::fail(exit_code, &msg);
+        }
         MultiTestSpec::PauseResume(exit_code) => {
             env::log("before");
             env::pause(exit_code);
@@ -247,6 +250,14 @@ fn main() {
             }
             env::log("Busy loop complete");
         }
+        MultiTestSpec::TimedLoop { iters } => {
+            use core::hint::black_box;
+            let _timer = env::CycleTimer::new("timed_loop");
+            let mut acc = 0u32;
+            for i in 0..iters {
+                acc = black_box(acc.wrapping_add(i));
+            }
+        }
         MultiTestSpec::BigInt { x, y, modulus } => {
             let mut result = [0u32; bigint::WIDTH_WORDS];
             unsafe {
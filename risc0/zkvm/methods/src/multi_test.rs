@@ -15,7 +15,7 @@
 // Definitions for test selection codes used by the "multi_test" test.
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use risc0_zkvm::{declare_syscall, sha::Digest};
 use risc0_zkvm_platform::syscall::bigint;
@@ -38,6 +38,10 @@ pub enum MultiTestSpec {
     Panic,
     Fault,
     Halt(u8),
+    Fail {
+        exit_code: u8,
+        msg: String,
+    },
     PauseResume(u8),
     ReadWriteMem {
         /// Tuples of (address, value). Zero means read the value and
@@ -86,6 +90,9 @@ pub enum MultiTestSpec {
         /// Busy loop until the guest has run for at least this number of cycles
         cycles: u64,
     },
+    TimedLoop {
+        iters: u32,
+    },
     LibM,
     Oom,
     OutOfBounds,
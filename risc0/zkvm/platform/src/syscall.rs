@@ -69,6 +69,31 @@ pub mod reg_abi {
     pub const REG_T5: usize = 30; // temporary
     pub const REG_T6: usize = 31; // temporary
     pub const REG_MAX: usize = 32; // maximum number of registers
+
+    const REG_ABI_NAMES: [&str; REG_MAX] = [
+        "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+        "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+        "t5", "t6",
+    ];
+
+    /// Return the conventional ABI name of the register at `idx`, for use in diagnostics.
+    ///
+    /// Register 8 is reported as `s0` rather than its alias `fp`.
+    ///
+    /// ```
+    /// use risc0_zkvm_platform::syscall::reg_abi::{reg_abi_name, REG_A0};
+    ///
+    /// assert_eq!(reg_abi_name(1), "ra");
+    /// assert_eq!(reg_abi_name(2), "sp");
+    /// assert_eq!(reg_abi_name(REG_A0), "a0");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not less than [REG_MAX].
+    pub const fn reg_abi_name(idx: usize) -> &'static str {
+        REG_ABI_NAMES[idx]
+    }
 }
 
 pub const DIGEST_WORDS: usize = 8;
@@ -158,6 +158,17 @@ pub fn exit(exit_code: u8) -> ! {
     unreachable!();
 }
 
+/// Terminate execution of the zkVM with a non-zero exit code, committing `msg` to the journal.
+///
+/// Unlike a panic, which aborts execution without producing a receipt, this produces a
+/// verifiable receipt whose claim records `exit_code` and whose journal contains `msg`,
+/// serialized as a `String`. This lets a guest attest to a verified failure, e.g. an input that
+/// failed validation, that the host can inspect.
+pub fn fail(exit_code: u8, msg: &str) -> ! {
+    commit(&msg);
+    exit(exit_code);
+}
+
 /// Pause the execution of the zkVM.
 ///
 /// Execution may be continued at a later time.
@@ -383,6 +394,42 @@ pub fn cycle_count() -> u64 {
     sys_cycle_count()
 }
 
+/// A guard that logs the number of cycles elapsed between its creation and
+/// when it is dropped.
+///
+/// ```no_run
+/// use risc0_zkvm::guest::env;
+///
+/// {
+///     let _timer = env::CycleTimer::new("hash_loop");
+///     // ... code to be timed ...
+/// } // logs "hash_loop: <n> cycles"
+/// ```
+///
+/// The cycle count is provided by the host and is not checked by the zkVM circuit. It also starts
+/// over at each segment boundary, so a timed section that spans segments is under-reported.
+pub struct CycleTimer<'a> {
+    label: &'a str,
+    start: u64,
+}
+
+impl<'a> CycleTimer<'a> {
+    /// Start a timer that logs with the given `label` when dropped.
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            start: cycle_count(),
+        }
+    }
+}
+
+impl Drop for CycleTimer<'_> {
+    fn drop(&mut self) {
+        let elapsed = cycle_count().saturating_sub(self.start);
+        log(&alloc::format!("{}: {elapsed} cycles", self.label));
+    }
+}
+
 /// Print a message to the debug console.
 pub fn log(msg: &str) {
     let msg = msg.as_bytes();
@@ -17,6 +17,7 @@
 use std::{
     cell::RefCell,
     collections::HashMap,
+    fs::File,
     io::{BufRead, BufReader, Cursor, Read, Write},
     mem,
     path::{Path, PathBuf},
@@ -24,7 +25,7 @@ use std::{
     sync::Arc,
 };
 
-use anyhow::Result;
+use anyhow::{Context as _, Result};
 use bytemuck::Pod;
 use bytes::Bytes;
 use risc0_zkp::core::digest::Digest;
@@ -34,7 +35,7 @@ use tempfile::TempDir;
 
 use crate::{
     host::client::{
-        posix_io::PosixIo,
+        posix_io::{PosixIo, RecordingReader},
         slice_io::{slice_io_from_fn, SliceIo, SliceIoTable},
     },
     serde::to_vec,
@@ -103,12 +104,16 @@ pub struct ExecutorEnv<'a> {
     pub(crate) posix_io: Rc<RefCell<PosixIo<'a>>>,
     pub(crate) slice_io: Rc<RefCell<SliceIoTable<'a>>>,
     pub(crate) input: Vec<u8>,
+    pub(crate) consumed_input: Option<Rc<RefCell<Vec<u8>>>>,
     pub(crate) trace: Vec<Rc<RefCell<dyn TraceCallback + 'a>>>,
     pub(crate) assumptions: Rc<RefCell<AssumptionReceipts>>,
     pub(crate) segment_path: Option<SegmentPath>,
     pub(crate) pprof_out: Option<PathBuf>,
     pub(crate) input_digest: Option<Digest>,
     pub(crate) coprocessor: Option<CoprocessorCallbackRef<'a>>,
+    pub(crate) rng_seed: Option<u64>,
+    pub(crate) max_syscall_return_words: Option<usize>,
+    pub(crate) max_sha_blocks: Option<u32>,
 }
 
 impl<'a> ExecutorEnv<'a> {
@@ -150,6 +155,12 @@ impl<'a> ExecutorEnvBuilder<'a> {
                 .with_read_fd(fileno::STDIN, reader);
         }
 
+        if let Some(record) = inner.consumed_input.clone() {
+            let mut posix_io = inner.posix_io.borrow_mut();
+            let reader = posix_io.get_reader(fileno::STDIN)?;
+            posix_io.with_read_fd(fileno::STDIN, RecordingReader::new(reader, record));
+        }
+
         if inner.pprof_out.is_none() {
             if let Ok(env_var) = std::env::var("RISC0_PPROF_OUT") {
                 inner.pprof_out = Some(env_var.into());
@@ -192,6 +203,35 @@ impl<'a> ExecutorEnvBuilder<'a> {
         self
     }
 
+    /// Set the maximum number of words a single software syscall may return to
+    /// the guest.
+    ///
+    /// A guest asking for more than this fails execution before the host
+    /// allocates a buffer for the reply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use risc0_zkvm::ExecutorEnv;
+    ///
+    /// let env = ExecutorEnv::builder()
+    ///     .max_syscall_return_words(1024 * 1024) // 4 MiB
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn max_syscall_return_words(&mut self, max_words: usize) -> &mut Self {
+        self.inner.max_syscall_return_words = Some(max_words);
+        self
+    }
+
+    /// Set the maximum number of blocks a single SHA-256 ecall may compress.
+    ///
+    /// A guest asking for more than this fails execution.
+    pub fn max_sha_blocks(&mut self, max_blocks: u32) -> &mut Self {
+        self.inner.max_sha_blocks = Some(max_blocks);
+        self
+    }
+
     /// Add environment variables to the guest environment.
     ///
     /// # Example
@@ -324,6 +364,18 @@ impl<'a> ExecutorEnvBuilder<'a> {
         self.read_fd(fileno::STDIN, BufReader::new(reader))
     }
 
+    /// Add a posix-style standard input that streams from the file at `path`.
+    ///
+    /// The file is read incrementally as the guest reads from stdin, so large inputs are not
+    /// buffered in host memory. Note that any input added with [Self::write],
+    /// [Self::write_slice], or [Self::write_frame] replaces this as stdin when the
+    /// [ExecutorEnv] is built.
+    pub fn stdin_from_file(&mut self, path: impl AsRef<Path>) -> Result<&mut Self> {
+        let file = File::open(path.as_ref())
+            .with_context(|| format!("failed to open {}", path.as_ref().display()))?;
+        Ok(self.stdin(file))
+    }
+
     /// Add a posix-style standard output.
     pub fn stdout(&mut self, writer: impl Write + 'a) -> &mut Self {
         self.write_fd(fileno::STDOUT, writer)
@@ -406,6 +458,27 @@ impl<'a> ExecutorEnvBuilder<'a> {
         self
     }
 
+    /// Record the bytes the guest reads from stdin.
+    ///
+    /// After execution, the recorded bytes are available from
+    /// [Session::consumed_input](crate::Session::consumed_input). This lets a
+    /// host check exactly which part of the provided input the guest consumed.
+    pub fn record_input(&mut self) -> &mut Self {
+        self.inner.consumed_input = Some(Rc::new(RefCell::new(Vec::new())));
+        self
+    }
+
+    /// Seed the randomness served to the guest by `sys_random`.
+    ///
+    /// By default the guest receives randomness from the host OS. With a seed,
+    /// the guest sees the same random bytes on every run, which is useful for
+    /// replaying an execution deterministically in tests. The bytes come from
+    /// ChaCha20, so a seed yields the same stream across releases.
+    pub fn rng_seed(&mut self, seed: u64) -> &mut Self {
+        self.inner.rng_seed = Some(seed);
+        self
+    }
+
     /// Set the input digest.
     pub fn input_digest(&mut self, digest: Digest) -> &mut Self {
         self.inner.input_digest = Some(digest);
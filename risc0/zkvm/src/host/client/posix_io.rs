@@ -97,3 +97,23 @@ impl<'a> PosixIo<'a> {
             .cloned()
     }
 }
+
+/// A reader that records every byte read through it into a shared buffer.
+pub(crate) struct RecordingReader<'a> {
+    inner: SharedRead<'a>,
+    record: Rc<RefCell<Vec<u8>>>,
+}
+
+impl<'a> RecordingReader<'a> {
+    pub fn new(inner: SharedRead<'a>, record: Rc<RefCell<Vec<u8>>>) -> Self {
+        Self { inner, record }
+    }
+}
+
+impl<'a> Read for RecordingReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let nread = self.inner.borrow_mut().read(buf)?;
+        self.record.borrow_mut().extend_from_slice(&buf[..nread]);
+        Ok(nread)
+    }
+}
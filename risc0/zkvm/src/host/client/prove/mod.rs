@@ -18,7 +18,7 @@ pub(crate) mod external;
 #[cfg(feature = "prove")]
 pub(crate) mod local;
 
-use std::{path::PathBuf, rc::Rc};
+use std::{path::PathBuf, rc::Rc, str::FromStr};
 
 use anyhow::{anyhow, Result};
 use risc0_build::risc0_data;
@@ -296,6 +296,16 @@ impl ProverOpts {
         }
     }
 
+    /// Construct [ProverOpts] from a `RISC0_PROVER`-style `backend[:hashfn]` string, such as
+    /// `local:poseidon2` or `ipc:sha-256`.
+    ///
+    /// The string is parsed as a [ProverConfig], and the result is equal to
+    /// [ProverConfig::prover_opts]: the default options with the hash function and matching
+    /// control IDs. Parse a [ProverConfig] directly to also keep the selected backend.
+    pub fn from_prover_spec(spec: &str) -> Result<Self> {
+        Ok(spec.parse::<ProverConfig>()?.prover_opts())
+    }
+
     /// Return [ProverOpts] with the hashfn set to the given value.
     pub fn with_hashfn(self, hashfn: String) -> Self {
         Self {
@@ -304,6 +314,27 @@ impl ProverOpts {
         }
     }
 
+    /// Return [ProverOpts] set up to prove with `hashfn`, with control IDs to match.
+    ///
+    /// Unlike [Self::with_hashfn], this also replaces the control IDs with those of `hashfn`, up
+    /// to the max segment po2 of these options. Only [ProverHashFn::Poseidon2] includes the
+    /// recursion programs.
+    pub fn with_prover_hashfn(self, hashfn: ProverHashFn) -> Self {
+        let control_ids = match hashfn {
+            ProverHashFn::Poseidon2 => {
+                crate::receipt::succinct::allowed_control_ids("poseidon2", self.max_segment_po2)
+                    .unwrap()
+                    .collect()
+            }
+            _ => risc0_circuit_rv32im::control_ids(hashfn.as_str(), self.max_segment_po2).collect(),
+        };
+        Self {
+            hashfn: hashfn.as_str().to_string(),
+            control_ids,
+            ..self
+        }
+    }
+
     /// Return [ProverOpts] with prove_guest_errors set to the given value.
     pub fn with_prove_guest_errors(self, prove_guest_errors: bool) -> Self {
         Self {
@@ -346,6 +377,150 @@ impl ProverOpts {
     }
 }
 
+/// A [Prover] implementation that can be selected with [prover_from_config].
+///
+/// This allows an application to choose a prover in code rather than through
+/// the `RISC0_PROVER` environment variable. The hash function is selected per
+/// proof with [ProverOpts::with_prover_hashfn].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProverBackend {
+    /// [BonsaiProver] to prove on Bonsai.
+    #[cfg(feature = "bonsai")]
+    Bonsai,
+
+    /// [ExternalProver] to prove using an `r0vm` sub-process.
+    Ipc,
+
+    /// LocalProver to prove locally in-process.
+    #[cfg(feature = "prove")]
+    Local,
+}
+
+impl FromStr for ProverBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            #[cfg(feature = "bonsai")]
+            "bonsai" => Ok(Self::Bonsai),
+            "ipc" => Ok(Self::Ipc),
+            #[cfg(feature = "prove")]
+            "local" => Ok(Self::Local),
+            _ => Err(anyhow!("Unsupported prover: {s}")),
+        }
+    }
+}
+
+/// A hash function that can be selected for proving with [ProverConfig].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProverHashFn {
+    /// Poseidon2, which supports compression via recursion.
+    Poseidon2,
+
+    /// SHA-256, which is fastest to prove but does not support recursion.
+    Sha256,
+
+    /// BLAKE2b, which does not support recursion.
+    Blake2b,
+}
+
+impl ProverHashFn {
+    /// Name of the hash function, as used in [ProverOpts::hashfn].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Poseidon2 => "poseidon2",
+            Self::Sha256 => "sha-256",
+            Self::Blake2b => "blake2b",
+        }
+    }
+}
+
+impl FromStr for ProverHashFn {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "poseidon2" => Ok(Self::Poseidon2),
+            "sha-256" => Ok(Self::Sha256),
+            "blake2b" => Ok(Self::Blake2b),
+            _ => Err(anyhow!("Unsupported hash function: {s}")),
+        }
+    }
+}
+
+/// Selects a [Prover] backend and hash function in code, rather than through
+/// the `RISC0_PROVER` environment variable.
+///
+/// Use [prover_from_config] to get the [Prover] and [ProverConfig::prover_opts]
+/// to get the options to prove with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProverConfig {
+    /// The [Prover] implementation to use.
+    pub backend: ProverBackend,
+
+    /// The hash function to use for the STARK proving protocol.
+    pub hashfn: ProverHashFn,
+}
+
+impl ProverConfig {
+    /// Construct a [ProverConfig] for `backend` using [ProverHashFn::Poseidon2].
+    pub fn new(backend: ProverBackend) -> Self {
+        Self {
+            backend,
+            hashfn: ProverHashFn::Poseidon2,
+        }
+    }
+
+    /// Return [ProverConfig] with the hashfn set to the given value.
+    pub fn with_hashfn(self, hashfn: ProverHashFn) -> Self {
+        Self { hashfn, ..self }
+    }
+
+    /// Return the default [ProverOpts], set up to prove with [Self::hashfn].
+    pub fn prover_opts(&self) -> ProverOpts {
+        ProverOpts::default().with_prover_hashfn(self.hashfn)
+    }
+}
+
+impl FromStr for ProverConfig {
+    type Err = anyhow::Error;
+
+    /// Parse a `backend[:hashfn]` string, such as `local:poseidon2` or `ipc`.
+    fn from_str(s: &str) -> Result<Self> {
+        let (backend, hashfn) = match s.split_once(':') {
+            Some((backend, hashfn)) => (backend, Some(hashfn)),
+            None => (s, None),
+        };
+        let config = Self::new(backend.parse()?);
+        Ok(match hashfn {
+            Some(hashfn) => config.with_hashfn(hashfn.parse()?),
+            None => config,
+        })
+    }
+}
+
+fn prover_for_backend(backend: ProverBackend) -> Result<Rc<dyn Prover>> {
+    Ok(match backend {
+        #[cfg(feature = "bonsai")]
+        ProverBackend::Bonsai => Rc::new(BonsaiProver::new("bonsai")),
+        ProverBackend::Ipc => Rc::new(ExternalProver::new("ipc", get_r0vm_path()?)),
+        #[cfg(feature = "prove")]
+        ProverBackend::Local => Rc::new(self::local::LocalProver::new("local")),
+    })
+}
+
+/// Return the [Prover] selected by `config`.
+///
+/// Unlike [default_prover], this does not consult any environment variables,
+/// except for `RISC0_SERVER_PATH` to locate `r0vm` for [ProverBackend::Ipc].
+/// The hash function is applied per proof, by proving with
+/// [ProverConfig::prover_opts].
+pub fn prover_from_config(config: &ProverConfig) -> Result<Rc<dyn Prover>> {
+    prover_for_backend(config.backend)
+}
+
 /// Return a default [Prover] based on environment variables and feature flags.
 ///
 /// The `RISC0_PROVER` environment variable, if specified, will select the
@@ -362,17 +537,21 @@ impl ProverOpts {
 ///   variables are set unless `RISC0_DEV_MODE` is enabled.
 /// * LocalProver if the `prove` feature flag is enabled.
 /// * [ExternalProver] otherwise.
+///
+/// # Panics
+///
+/// Panics if `RISC0_PROVER` names an unsupported prover. Use
+/// [try_default_prover] to handle this as an error.
 pub fn default_prover() -> Rc<dyn Prover> {
+    try_default_prover().unwrap()
+}
+
+/// Return a default [Prover] like [default_prover], or an error if
+/// `RISC0_PROVER` names an unsupported prover.
+pub fn try_default_prover() -> Result<Rc<dyn Prover>> {
     let explicit = std::env::var("RISC0_PROVER").unwrap_or_default();
     if !explicit.is_empty() {
-        return match explicit.to_lowercase().as_str() {
-            #[cfg(feature = "bonsai")]
-            "bonsai" => Rc::new(BonsaiProver::new("bonsai")),
-            "ipc" => Rc::new(ExternalProver::new("ipc", get_r0vm_path().unwrap())),
-            #[cfg(feature = "prove")]
-            "local" => Rc::new(self::local::LocalProver::new("local")),
-            _ => unimplemented!("Unsupported prover: {explicit}"),
-        };
+        return prover_for_backend(explicit.parse()?);
     }
 
     #[cfg(feature = "bonsai")]
@@ -381,16 +560,16 @@ pub fn default_prover() -> Rc<dyn Prover> {
             && std::env::var("BONSAI_API_URL").is_ok()
             && std::env::var("BONSAI_API_KEY").is_ok()
         {
-            return Rc::new(BonsaiProver::new("bonsai"));
+            return Ok(Rc::new(BonsaiProver::new("bonsai")));
         }
     }
 
     if cfg!(feature = "prove") {
         #[cfg(feature = "prove")]
-        return Rc::new(self::local::LocalProver::new("local"));
+        return Ok(Rc::new(self::local::LocalProver::new("local")));
     }
 
-    Rc::new(ExternalProver::new("ipc", get_r0vm_path().unwrap()))
+    Ok(Rc::new(ExternalProver::new("ipc", get_r0vm_path()?)))
 }
 
 /// Return a default [Executor] based on environment variables and feature
@@ -14,6 +14,8 @@
 
 //! Struct containing information about a prover's execution including the receipt.
 
+use serde::Serialize;
+
 use crate::Receipt;
 
 /// Information returned by the prover including receipt as well as other information useful for debugging
@@ -25,7 +27,7 @@ pub struct ProveInfo {
 }
 
 /// Struct containing information about a prover's cycle count after running the guest program
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct SessionStats {
     /// Count of segments in this proof request
     pub segments: usize,
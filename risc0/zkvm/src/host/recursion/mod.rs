@@ -31,6 +31,9 @@ mod tests;
 pub use crate::receipt::merkle::{MerkleGroup, MerkleProof};
 pub use risc0_circuit_recursion::control_id::{ALLOWED_CONTROL_IDS, ALLOWED_CONTROL_ROOT};
 
+use risc0_circuit_recursion::control_id::{MIN_LIFT_PO2, POSEIDON2_CONTROL_IDS};
+use risc0_zkp::core::digest::Digest;
+
 #[cfg(test)]
 #[cfg(feature = "prove")]
 pub use self::prove::test_zkr;
@@ -40,3 +43,22 @@ pub use self::prove::{identity_p254, join, lift, resolve, Prover, RECURSION_PO2}
 pub use risc0_circuit_recursion::prove::{
     poseidon254_hal_pair, poseidon2_hal_pair, sha256_hal_pair, Program,
 };
+
+/// Returns the Poseidon2 control ID of the lift program for a segment of the given po2.
+///
+/// Each lift program verifies rv32im segments of exactly one po2, so this looks up the program
+/// named `lift_{po2}.zkr`. Returns `None` if there is no lift program for `po2`.
+pub fn lift_control_id(po2: usize) -> Option<Digest> {
+    if po2 < MIN_LIFT_PO2 {
+        return None;
+    }
+    let name = format!("lift_{po2}.zkr");
+    POSEIDON2_CONTROL_IDS
+        .iter()
+        .find_map(|(n, id)| (*n == name).then_some(*id))
+}
+
+/// Returns the position of the control ID `id` in [ALLOWED_CONTROL_IDS].
+pub fn control_id_index(id: &Digest) -> Option<usize> {
+    ALLOWED_CONTROL_IDS.iter().position(|x| x == id)
+}
@@ -24,14 +24,16 @@ use risc0_zkp::{
 use risc0_zkvm_methods::{multi_test::MultiTestSpec, MULTI_TEST_ELF, MULTI_TEST_ID};
 use test_log::test;
 
-use super::{identity_p254, join, lift, prove::zkr, MerkleGroup, Prover};
+use super::{
+    control_id_index, identity_p254, join, lift, lift_control_id, prove::zkr, MerkleGroup, Prover,
+};
 use crate::{
     default_prover, get_prover_server,
     receipt_claim::{MaybePruned, Unknown},
     sha::{self, Digestible},
     ExecutorEnv, ExecutorImpl, InnerReceipt, ProverOpts, Receipt, SegmentReceipt, Session,
-    SuccinctReceipt, SuccinctReceiptVerifierParameters, VerifierContext, ALLOWED_CONTROL_ROOT,
-    RECURSION_PO2,
+    SuccinctReceipt, SuccinctReceiptVerifierParameters, VerifierContext, ALLOWED_CONTROL_IDS,
+    ALLOWED_CONTROL_ROOT, RECURSION_PO2,
 };
 use risc0_circuit_recursion::prove::{poseidon254_hal_pair, poseidon2_hal_pair};
 
@@ -392,3 +394,20 @@ fn stable_root() {
         digest!("8b6dcf11d463ac455361b41fb3ed053febb817491bdea00fdb340e45013b852e")
     );
 }
+
+#[test]
+fn lift_control_id_range() {
+    assert_eq!(lift_control_id(13), None);
+    assert_eq!(lift_control_id(22), None);
+
+    let indices: Vec<usize> = (14..=21)
+        .map(|po2| {
+            let id = lift_control_id(po2).unwrap();
+            control_id_index(&id).unwrap()
+        })
+        .collect();
+    assert!(indices.windows(2).all(|w| w[0] < w[1]));
+
+    assert_eq!(control_id_index(&ALLOWED_CONTROL_IDS[0]), Some(0));
+    assert_eq!(control_id_index(&Digest::ZERO), None);
+}
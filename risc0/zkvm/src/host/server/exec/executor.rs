@@ -136,12 +136,19 @@ impl<'a> ExecutorImpl<'a> {
             .unwrap_or(DEFAULT_SEGMENT_LIMIT_PO2 as u32) as usize;
 
         let mut refs = Vec::new();
+        let mut segment_cycles = Vec::new();
         let mut exec = Executor::new(
             self.image.clone(),
             self,
             self.env.input_digest,
             self.env.trace.clone(),
         );
+        if let Some(max_words) = self.env.max_syscall_return_words {
+            exec = exec.with_max_syscall_return_words(max_words);
+        }
+        if let Some(max_blocks) = self.env.max_sha_blocks {
+            exec = exec.with_max_sha_blocks(max_blocks);
+        }
 
         let start_time = Instant::now();
         let result = exec.run(segment_limit_po2, self.env.session_limit, |inner| {
@@ -177,6 +184,11 @@ impl<'a> ExecutorImpl<'a> {
                 inner,
                 output,
             };
+            segment_cycles.push((
+                segment.index as usize,
+                segment.inner.insn_cycles as u64,
+                segment.po2(),
+            ));
             let segment_ref = callback(segment)?;
             refs.push(segment_ref);
             Ok(())
@@ -200,6 +212,15 @@ impl<'a> ExecutorImpl<'a> {
         let assumptions = self.syscall_table.assumptions_used.take();
         let pending_zkrs = self.syscall_table.pending_zkrs.take();
 
+        // Take the input consumed since the last run, so a resumed session only
+        // reports its own reads.
+        let consumed_input = self
+            .env
+            .consumed_input
+            .as_ref()
+            .map(|record| record.take())
+            .unwrap_or_default();
+
         if let Some(profiler) = self.profiler.take() {
             let report = profiler.borrow_mut().finalize_to_vec();
             std::fs::write(self.env.pprof_out.as_ref().unwrap(), report)?;
@@ -216,6 +237,8 @@ impl<'a> ExecutorImpl<'a> {
             assumptions,
             result.user_cycles,
             result.total_cycles,
+            segment_cycles,
+            consumed_input,
             result.pre_state,
             result.post_state,
             pending_zkrs,
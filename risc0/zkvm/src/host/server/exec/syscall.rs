@@ -23,6 +23,8 @@ use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
 
 use anyhow::{anyhow, bail, Result};
 use bytes::Bytes;
+use rand::{RngCore as _, SeedableRng as _};
+use rand_chacha::ChaCha20Rng;
 use risc0_circuit_rv32im::prove::emu::addr::ByteAddr;
 use risc0_zkp::core::digest::Digest;
 use risc0_zkvm_platform::{
@@ -137,7 +139,7 @@ impl<'a> SyscallTable<'a> {
             .with_syscall(SYS_PANIC, SysPanic)
             .with_syscall(SYS_PIPE, SysPipe::default())
             .with_syscall(SYS_PROVE_ZKR, SysProveZkr)
-            .with_syscall(SYS_RANDOM, SysRandom)
+            .with_syscall(SYS_RANDOM, SysRandom::new(env.rng_seed))
             .with_syscall(SYS_READ, SysRead)
             .with_syscall(SYS_VERIFY_INTEGRITY, SysVerify)
             .with_syscall(SYS_WRITE, SysWrite);
@@ -221,7 +223,16 @@ impl Syscall for SysPanic {
     }
 }
 
-pub(crate) struct SysRandom;
+/// Fills the guest buffer from the host OS, or from a seeded RNG when
+/// [ExecutorEnvBuilder::rng_seed](crate::ExecutorEnvBuilder::rng_seed) is set.
+pub(crate) struct SysRandom(Option<ChaCha20Rng>);
+
+impl SysRandom {
+    fn new(seed: Option<u64>) -> Self {
+        Self(seed.map(ChaCha20Rng::seed_from_u64))
+    }
+}
+
 impl Syscall for SysRandom {
     fn syscall(
         &mut self,
@@ -230,10 +241,12 @@ impl Syscall for SysRandom {
         to_guest: &mut [u32],
     ) -> Result<(u32, u32)> {
         tracing::debug!("SYS_RANDOM: {}", to_guest.len());
-        let mut rand_buf = vec![0u8; to_guest.len() * WORD_SIZE];
-        getrandom::getrandom(rand_buf.as_mut_slice())?;
-        bytemuck::cast_slice_mut(to_guest).clone_from_slice(rand_buf.as_slice());
-        Ok((0, 0))
+        let rand_buf: &mut [u8] = bytemuck::cast_slice_mut(to_guest);
+        match &mut self.0 {
+            Some(rng) => rng.fill_bytes(rand_buf),
+            None => getrandom::getrandom(rand_buf)?,
+        }
+        Ok((rand_buf.len() as u32, 0))
     }
 }
 
@@ -351,14 +364,17 @@ impl Syscall for SysRead {
         let reader = ctx.syscall_table().posix_io.borrow().get_reader(fd)?;
 
         // So that we don't have to deal with short reads, keep
-        // reading until we get EOF or fill the buffer.
+        // reading until we get EOF or fill the buffer. Reads interrupted
+        // by a signal are retried rather than failing the guest.
         let read_all = |mut buf: &mut [u8]| -> Result<usize> {
             let mut tot_nread = 0;
             while !buf.is_empty() {
-                let nread = reader.borrow_mut().read(buf)?;
-                if nread == 0 {
-                    break;
-                }
+                let nread = match reader.borrow_mut().read(buf) {
+                    Ok(0) => break,
+                    Ok(nread) => nread,
+                    Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(err) => return Err(err.into()),
+                };
                 tot_nread += nread;
                 (_, buf) = buf.split_at_mut(nread);
             }
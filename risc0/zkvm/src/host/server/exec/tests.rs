@@ -14,24 +14,33 @@
 
 use std::{
     collections::{BTreeMap, HashSet},
-    io::Cursor,
+    io::{BufReader, Cursor, Read, Write as _},
     str::from_utf8,
     sync::Mutex,
 };
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use bytes::Bytes;
 use risc0_binfmt::{MemoryImage, Program};
+use risc0_circuit_rv32im::prove::emu::{
+    addr::ByteAddr,
+    exec::{Syscall as NewSyscall, SyscallContext as NewSyscallContext},
+};
 use risc0_zkvm_methods::{
     multi_test::{MultiTestSpec, SYS_MULTI_TEST, SYS_MULTI_TEST_WORDS},
-    BLST_ELF, HEAP_ELF, HELLO_COMMIT_ELF, MULTI_TEST_ELF, RAND_ELF, SLICE_IO_ELF, STANDARD_LIB_ELF,
-    ZKVM_527_ELF,
+    BLST_ELF, HEAP_ELF, HELLO_COMMIT_ELF, HELLO_COMMIT_ID, MULTI_TEST_ELF, RAND_ELF, SLICE_IO_ELF,
+    STANDARD_LIB_ELF, ZKVM_527_ELF,
+};
+use risc0_zkvm_platform::{
+    declare_syscall, fileno,
+    syscall::{nr::SYS_RANDOM, reg_abi::REG_A3},
+    PAGE_SIZE, WORD_SIZE,
 };
-use risc0_zkvm_platform::{fileno, syscall::nr::SYS_RANDOM, PAGE_SIZE, WORD_SIZE};
 use sha2::{Digest as _, Sha256};
 use test_log::test;
 
 use crate::{
+    compute_image_id,
     host::server::{
         exec::{
             profiler::{Frame, Profiler},
@@ -41,7 +50,7 @@ use crate::{
     },
     serde::to_vec,
     sha::{Digest, Digestible},
-    ExecutorEnv, ExecutorImpl, ExitCode,
+    ExecutorEnv, ExecutorImpl, ExitCode, ProverOpts,
 };
 
 fn run_test(spec: MultiTestSpec) {
@@ -154,6 +163,92 @@ fn system_split() {
     );
     assert_eq!(segments[0].index, 0);
     assert_eq!(segments[1].index, 1);
+
+    let segment_cycles = session.segment_cycles();
+    assert_eq!(segment_cycles.len(), 2);
+    for (segment, (idx, cycles, po2)) in segments.iter().zip(&segment_cycles) {
+        assert_eq!(*idx, segment.index as usize);
+        assert_eq!(*po2, segment.po2());
+        assert!(*cycles < 1u64 << *po2);
+    }
+    assert_eq!(
+        segment_cycles
+            .iter()
+            .map(|(_, cycles, _)| cycles)
+            .sum::<u64>(),
+        session.user_cycles
+    );
+}
+
+#[test]
+fn session_stats_json() {
+    let session = ExecutorImpl::from_elf(ExecutorEnv::default(), HELLO_COMMIT_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    let stats: serde_json::Value = serde_json::to_value(session.stats()).unwrap();
+    assert_eq!(stats["segments"], session.segments.len());
+    assert_eq!(stats["total_cycles"], session.total_cycles);
+    assert_eq!(stats["user_cycles"], session.user_cycles);
+}
+
+#[test]
+fn compute_image_id_matches_session() {
+    let image_id = compute_image_id(HELLO_COMMIT_ELF).unwrap();
+    assert_eq!(image_id, Digest::from(HELLO_COMMIT_ID));
+
+    let session = ExecutorImpl::from_elf(ExecutorEnv::default(), HELLO_COMMIT_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.pre_state.digest(), image_id);
+}
+
+#[test]
+fn estimate_proving_memory() {
+    let run = |segment_limit_po2| {
+        let env = ExecutorEnv::builder()
+            .write(&MultiTestSpec::BusyLoop { cycles: 1 << 16 })
+            .unwrap()
+            .segment_limit_po2(segment_limit_po2)
+            .build()
+            .unwrap();
+        ExecutorImpl::from_elf(env, MULTI_TEST_ELF)
+            .unwrap()
+            .run()
+            .unwrap()
+    };
+    let opts = ProverOpts::composite();
+    let small = run(14).estimate_proving_memory(&opts);
+    let large = run(16).estimate_proving_memory(&opts);
+    assert!(large > small, "expected {large} > {small}");
+}
+
+#[test]
+fn cycle_timer() {
+    let mut stdout: Vec<u8> = Vec::new();
+    {
+        let env = ExecutorEnv::builder()
+            .write(&MultiTestSpec::TimedLoop { iters: 1000 })
+            .unwrap()
+            .stdout(&mut stdout)
+            .build()
+            .unwrap();
+        let session = ExecutorImpl::from_elf(env, MULTI_TEST_ELF)
+            .unwrap()
+            .run()
+            .unwrap();
+        assert_eq!(session.exit_code, ExitCode::Halted(0));
+    }
+    let stdout = String::from_utf8(stdout).unwrap();
+    let cycles: u64 = stdout
+        .lines()
+        .find_map(|line| line.split_once("timed_loop: "))
+        .and_then(|(_, rest)| rest.strip_suffix(" cycles"))
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(cycles >= 1000, "{stdout}");
 }
 
 #[test]
@@ -461,6 +556,77 @@ fn unaligned_short_read() {
     assert_eq!(actual, expected, "pos and lens: {spec:?}");
 }
 
+#[test]
+fn syscall_return_limit() {
+    const FD: u32 = 123;
+    let readbuf = vec![0xAAu8; 1024];
+
+    let spec = MultiTestSpec::SysRead {
+        fd: FD,
+        buf: vec![0; readbuf.len()],
+        pos_and_len: vec![(0, readbuf.len() as u32)],
+    };
+    let env = ExecutorEnv::builder()
+        .read_fd(FD, readbuf.as_slice())
+        .max_syscall_return_words(64)
+        .write(&spec)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut exec = ExecutorImpl::from_elf(env, MULTI_TEST_ELF).unwrap();
+    let err = exec.run().err().unwrap();
+    assert!(
+        err.to_string()
+            .contains("into_guest length (256 words) exceeds limit (64 words)"),
+        "{err}"
+    );
+}
+
+#[test]
+fn sys_read_interrupted() {
+    const FD: u32 = 123;
+
+    // Fails the first read with EINTR, then reads normally.
+    struct InterruptOnce<R> {
+        interrupted: bool,
+        inner: R,
+    }
+
+    impl<R: Read> Read for InterruptOnce<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if !self.interrupted {
+                self.interrupted = true;
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            self.inner.read(buf)
+        }
+    }
+
+    let readbuf: &[u8] = b"123456789";
+    let spec = MultiTestSpec::SysRead {
+        fd: FD,
+        buf: vec![0; readbuf.len()],
+        pos_and_len: vec![(0, readbuf.len() as u32)],
+    };
+    let reader = InterruptOnce {
+        interrupted: false,
+        inner: readbuf,
+    };
+    let env = ExecutorEnv::builder()
+        .read_fd(FD, BufReader::new(reader))
+        .write(&spec)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut exec = ExecutorImpl::from_elf(env, MULTI_TEST_ELF).unwrap();
+    let session = exec.run().unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+
+    let (actual, num_read): (Vec<u8>, Vec<usize>) = session.journal.unwrap().decode().unwrap();
+    assert_eq!(num_read, [readbuf.len()]);
+    assert_eq!(actual, readbuf);
+}
+
 #[test]
 fn large_io_words() {
     const FD: u32 = 123;
@@ -485,6 +651,64 @@ fn large_io_words() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn record_input() {
+    // The guest reads the spec from stdin, followed by 40 of the 100 payload bytes.
+    let spec = to_vec(&MultiTestSpec::EchoWords {
+        fd: fileno::STDIN,
+        nwords: 10,
+    })
+    .unwrap();
+    let data: Vec<u8> = (0..100u8).collect();
+    let env = ExecutorEnv::builder()
+        .write_slice(&spec)
+        .write_slice(&data)
+        .record_input()
+        .build()
+        .unwrap();
+    let session = ExecutorImpl::from_elf(env, MULTI_TEST_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(0));
+
+    let spec_bytes: &[u8] = bytemuck::cast_slice(&spec);
+    let (consumed_spec, consumed_data) = session.consumed_input().split_at(spec_bytes.len());
+    assert_eq!(consumed_spec, spec_bytes);
+    assert_eq!(consumed_data, &data[..40]);
+}
+
+#[test]
+fn stdin_from_file() {
+    // The guest reads the spec from stdin, followed by the payload to echo.
+    let spec = to_vec(&MultiTestSpec::EchoStdout {
+        nbytes: 1000,
+        fd: fileno::STDIN,
+    })
+    .unwrap();
+    let data: Vec<u8> = (0..1u32 << 20).map(|i| (i % 251) as u8).collect();
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(bytemuck::cast_slice(&spec)).unwrap();
+    file.write_all(&data).unwrap();
+
+    let mut stdout: Vec<u8> = Vec::new();
+    {
+        let env = ExecutorEnv::builder()
+            .stdin_from_file(file.path())
+            .unwrap()
+            .stdout(&mut stdout)
+            .build()
+            .unwrap();
+        let session = ExecutorImpl::from_elf(env, MULTI_TEST_ELF)
+            .unwrap()
+            .run()
+            .unwrap();
+        assert_eq!(session.exit_code, ExitCode::Halted(0));
+    }
+    assert_eq!(stdout.len(), data.len());
+    assert_eq!(Sha256::digest(&stdout), Sha256::digest(&data));
+}
+
 #[test]
 fn large_io_bytes() {
     const FD: u32 = 123;
@@ -745,6 +969,21 @@ fn large_sha() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn sha_block_limit() {
+    let env = ExecutorEnv::builder()
+        .max_sha_blocks(4)
+        .write(&MultiTestSpec::ShaDigest {
+            data: vec![0u8; 1024],
+        })
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut exec = ExecutorImpl::from_elf(env, MULTI_TEST_ELF).unwrap();
+    let err = exec.run().err().unwrap();
+    assert!(err.to_string().contains("exceeds limit (4)"), "{err}");
+}
+
 #[test]
 fn std_stdio() {
     const STDIN: &str = "Hello world from stdin!\n";
@@ -865,6 +1104,42 @@ fn random() {
     run_test(MultiTestSpec::DoRandom);
 }
 
+#[test]
+fn random_with_seed() {
+    let run = |seed| {
+        let env = ExecutorEnv::builder()
+            .rng_seed(seed)
+            .write(&MultiTestSpec::DoRandom)
+            .unwrap()
+            .build()
+            .unwrap();
+        let session = ExecutorImpl::from_elf(env, MULTI_TEST_ELF)
+            .unwrap()
+            .run()
+            .unwrap();
+        session.journal.unwrap().bytes
+    };
+
+    let journal = run(7);
+    assert!(!journal.is_empty());
+    assert_eq!(journal, run(7));
+    assert_ne!(journal, run(8));
+
+    // Call the handler directly to check the number of bytes it reports.
+    let sys_random = |seed| {
+        let env = ExecutorEnv::builder().rng_seed(seed).build().unwrap();
+        let mut exec = ExecutorImpl::from_elf(env, MULTI_TEST_ELF).unwrap();
+        let mut to_guest = [0u32; 4];
+        let regs = exec
+            .syscall(SYS_RANDOM.as_str(), &mut StubContext, &mut to_guest)
+            .unwrap();
+        assert_eq!(regs, ((to_guest.len() * WORD_SIZE) as u32, 0));
+        to_guest
+    };
+    assert_eq!(sys_random(7), sys_random(7));
+    assert_ne!(sys_random(7), sys_random(8));
+}
+
 #[test]
 #[should_panic(expected = "WARNING: `getrandom()` called from guest.")]
 fn getrandom_panic() {
@@ -1111,6 +1386,74 @@ fn post_state_digest_randomization() {
     assert_eq!(post_state_digests.len(), 1);
 }
 
+// A syscall context for calling syscall handlers directly, without a running guest.
+struct StubContext;
+impl NewSyscallContext for StubContext {
+    fn peek_register(&mut self, idx: usize) -> Result<u32> {
+        Ok(idx as u32 * 10)
+    }
+
+    fn peek_u32(&mut self, _addr: ByteAddr) -> Result<u32> {
+        bail!("not used by these tests")
+    }
+
+    fn peek_u8(&mut self, _addr: ByteAddr) -> Result<u8> {
+        bail!("not used by these tests")
+    }
+
+    fn peek_page(&mut self, _page_idx: u32) -> Result<Vec<u8>> {
+        bail!("not used by these tests")
+    }
+
+    fn get_cycle(&self) -> u64 {
+        0
+    }
+
+    fn get_pc(&self) -> u32 {
+        0x1000
+    }
+}
+
+#[test]
+fn syscall_table_dispatch() {
+    declare_syscall!(SYS_TEST_ECHO);
+
+    // Checks the arguments it was dispatched with and echoes them back.
+    struct Echo;
+    impl Syscall for Echo {
+        fn syscall(
+            &mut self,
+            syscall: &str,
+            ctx: &mut dyn SyscallContext,
+            to_guest: &mut [u32],
+        ) -> Result<(u32, u32)> {
+            assert_eq!(syscall, SYS_TEST_ECHO.as_str());
+            assert_eq!(to_guest, &[1, 2, 3, 4]);
+            let pc = ctx.get_pc();
+            for (i, word) in to_guest.iter_mut().enumerate() {
+                *word = pc + i as u32;
+            }
+            Ok((to_guest.len() as u32, ctx.load_register(REG_A3)))
+        }
+    }
+
+    let mut exec = ExecutorImpl::from_elf(ExecutorEnv::default(), MULTI_TEST_ELF).unwrap();
+    exec.syscall_table.with_syscall(SYS_TEST_ECHO, Echo);
+
+    let mut to_guest = [1, 2, 3, 4];
+    let regs = exec
+        .syscall(SYS_TEST_ECHO.as_str(), &mut StubContext, &mut to_guest)
+        .unwrap();
+    assert_eq!(regs, (4, REG_A3 as u32 * 10));
+    assert_eq!(to_guest, [0x1000, 0x1001, 0x1002, 0x1003]);
+
+    let err = exec
+        .syscall("unknown", &mut StubContext, &mut [])
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("Unknown syscall"));
+}
+
 #[test]
 fn aligned_alloc() {
     run_test(MultiTestSpec::AlignedAlloc);
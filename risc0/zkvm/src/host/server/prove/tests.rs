@@ -23,8 +23,10 @@ use test_log::test;
 use super::get_prover_server;
 use crate::{
     host::server::testutils,
+    prover_from_config,
     serde::{from_slice, to_vec},
-    ExecutorEnv, ExecutorImpl, ExitCode, ProveInfo, ProverOpts, Receipt, Session, VerifierContext,
+    verify_batch, BatchVerifyError, ExecutorEnv, ExecutorImpl, ExitCode, ProveInfo, ProverBackend,
+    ProverConfig, ProverHashFn, ProverOpts, Receipt, ReceiptKind, Session, VerifierContext,
 };
 
 fn prove_session_fast(session: &Session) -> Receipt {
@@ -45,6 +47,54 @@ fn prove_nothing(hashfn: &str) -> Result<ProveInfo> {
     get_prover_server(&opts).unwrap().prove(env, MULTI_TEST_ELF)
 }
 
+#[test]
+fn prover_opts_from_spec() {
+    let opts = ProverOpts::from_prover_spec("local:poseidon2").unwrap();
+    assert_eq!(opts.hashfn, "poseidon2");
+    assert_eq!(opts.control_ids, ProverOpts::default().control_ids);
+    let opts = ProverOpts::from_prover_spec("LOCAL:sha-256").unwrap();
+    assert_eq!(opts.hashfn, "sha-256");
+    assert_eq!(opts.control_ids, ProverOpts::fast().control_ids);
+    assert!(opts.receipt_kind == ReceiptKind::Composite);
+    assert_eq!(
+        ProverOpts::from_prover_spec("local").unwrap().hashfn,
+        "poseidon2"
+    );
+
+    assert!(ProverOpts::from_prover_spec("local:").is_err());
+    assert!(ProverOpts::from_prover_spec("local:blake3").is_err());
+    assert!(ProverOpts::from_prover_spec("unknown:poseidon2").is_err());
+}
+
+#[test]
+fn prover_config() {
+    let config: ProverConfig = "local:sha-256".parse().unwrap();
+    assert_eq!(
+        config,
+        ProverConfig::new(ProverBackend::Local).with_hashfn(ProverHashFn::Sha256)
+    );
+    assert_eq!(
+        "LOCAL".parse::<ProverConfig>().unwrap(),
+        ProverConfig::new(ProverBackend::Local)
+    );
+    assert_eq!(
+        "ipc:BLAKE2B".parse::<ProverConfig>().unwrap(),
+        ProverConfig::new(ProverBackend::Ipc).with_hashfn(ProverHashFn::Blake2b)
+    );
+    assert!("local:".parse::<ProverConfig>().is_err());
+    assert!("local:md5".parse::<ProverConfig>().is_err());
+    assert!("unknown".parse::<ProverConfig>().is_err());
+
+    let opts = config.prover_opts();
+    assert_eq!(opts.hashfn, "sha-256");
+    assert_eq!(opts.control_ids, ProverOpts::fast().control_ids);
+
+    let prover = prover_from_config(&config).unwrap();
+    assert_eq!(prover.get_name(), "local");
+    let prover = prover_from_config(&ProverConfig::new(ProverBackend::Ipc)).unwrap();
+    assert_eq!(prover.get_name(), "ipc");
+}
+
 #[test]
 fn prove_nothing_succinct() {
     let env = ExecutorEnv::builder()
@@ -53,14 +103,38 @@ fn prove_nothing_succinct() {
         .build()
         .unwrap();
     let opts = ProverOpts::succinct();
-    get_prover_server(&opts)
+    let receipt = get_prover_server(&opts)
         .unwrap()
         .prove(env, MULTI_TEST_ELF)
         .unwrap()
-        .receipt
-        .inner
-        .succinct()
-        .unwrap(); // ensure that we got a succinct receipt.
+        .receipt;
+    receipt.inner.succinct().unwrap(); // ensure that we got a succinct receipt.
+    assert_eq!(receipt.segment_count(), None);
+}
+
+#[test]
+fn verify_with_allowed_ids() {
+    let env = ExecutorEnv::builder()
+        .write(&MultiTestSpec::DoNothing)
+        .unwrap()
+        .build()
+        .unwrap();
+    let receipt = get_prover_server(&ProverOpts::succinct())
+        .unwrap()
+        .prove(env, MULTI_TEST_ELF)
+        .unwrap()
+        .receipt;
+    let control_id = receipt.inner.succinct().unwrap().control_id;
+
+    receipt
+        .verify_with_allowed_ids(MULTI_TEST_ID, &[control_id])
+        .unwrap();
+    assert_eq!(
+        receipt
+            .verify_with_allowed_ids(MULTI_TEST_ID, &[Digest::ZERO])
+            .unwrap_err(),
+        VerificationError::ControlIdNotAllowed { control_id }
+    );
 }
 
 #[test]
@@ -90,6 +164,43 @@ fn check_image_id() {
     ));
 }
 
+#[test]
+fn receipt_verify_batch() {
+    let receipt = prove_nothing("sha-256").unwrap().receipt;
+    let mut receipts = vec![receipt.clone(), receipt.clone(), receipt];
+    Receipt::verify_batch(&receipts, MULTI_TEST_ID).unwrap();
+
+    receipts[1].journal.bytes = vec![1, 2, 3, 4];
+    let BatchVerifyError { failed } = Receipt::verify_batch(&receipts, MULTI_TEST_ID).unwrap_err();
+    assert_eq!(
+        failed.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        vec![1]
+    );
+    assert!(matches!(
+        failed[0].1,
+        VerificationError::ClaimDigestMismatch { .. }
+    ));
+
+    // With receipts 0 and 1 both invalid, only the first failure is reported.
+    receipts[0].journal.bytes = vec![5, 6, 7, 8];
+    let BatchVerifyError { failed } = Receipt::verify_batch(&receipts, MULTI_TEST_ID).unwrap_err();
+    assert_eq!(
+        failed.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        vec![0]
+    );
+
+    // The free verify_batch checks every receipt instead.
+    let pairs: Vec<(Receipt, Digest)> = receipts
+        .into_iter()
+        .map(|receipt| (receipt, MULTI_TEST_ID.into()))
+        .collect();
+    let BatchVerifyError { failed } = verify_batch(&pairs).unwrap_err();
+    assert_eq!(
+        failed.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+}
+
 #[test]
 fn sha_basics() {
     fn run_sha(msg: &str) -> String {
@@ -403,6 +514,32 @@ fn pause_exit_nonzero() {
     prove_session_fast(&session);
 }
 
+#[test]
+fn guest_fail() {
+    let env = ExecutorEnv::builder()
+        .write(&MultiTestSpec::Fail {
+            exit_code: 2,
+            msg: "bad input".to_string(),
+        })
+        .unwrap()
+        .build()
+        .unwrap();
+    let session = ExecutorImpl::from_elf(env, MULTI_TEST_ELF)
+        .unwrap()
+        .run()
+        .unwrap();
+    assert_eq!(session.exit_code, ExitCode::Halted(2));
+
+    let receipt = prove_session_fast(&session);
+    receipt
+        .verify_integrity_with_context(&VerifierContext::default())
+        .unwrap();
+    let claim = receipt.claim().unwrap().value().unwrap();
+    assert_eq!(claim.exit_code, ExitCode::Halted(2));
+    let msg: String = receipt.journal.decode().unwrap();
+    assert_eq!(msg, "bad input");
+}
+
 #[test]
 fn continuation() {
     const COUNT: usize = 2; // Number of total chunks to aim for.
@@ -430,6 +567,7 @@ fn continuation() {
     assert_eq!(final_segment.inner.exit_code, ExitCode::Halted(0));
 
     let receipt = prove_session_fast(&session);
+    assert_eq!(receipt.segment_count(), Some(COUNT));
     for (idx, receipt) in receipt
         .inner
         .composite()
@@ -26,12 +26,20 @@ use crate::{
     host::{
         client::env::{ProveZkrRequest, SegmentPath},
         prove_info::SessionStats,
+        recursion::RECURSION_PO2,
     },
     sha::Digest,
-    Assumption, AssumptionReceipt, Assumptions, ExitCode, Journal, MaybePruned, Output,
-    ReceiptClaim,
+    Assumption, AssumptionReceipt, Assumptions, ExitCode, Journal, MaybePruned, Output, ProverOpts,
+    ReceiptClaim, ReceiptKind,
 };
 
+// Rough peak prover memory per padded cycle of the segment being proven. The local proving guide
+// (website/api/generating-proofs/local-proving.md) advises lowering the segment limit when less
+// than 10 GB is available, i.e. a segment at the default po2 of 20 needs about 10 GB:
+// 10 GiB / 2^20 cycles = 10 KiB per cycle. The prover allocates for the whole 2^po2 trace, so
+// this is applied to padded cycles, not used cycles.
+const PROVING_BYTES_PER_CYCLE: u64 = 10 * 1024;
+
 #[derive(Clone, Default, Serialize, Deserialize, Debug)]
 pub struct PageFaults {
     pub(crate) reads: BTreeSet<u32>,
@@ -78,6 +86,14 @@ pub struct Session {
     /// associated with continuations and padding up to the nearest power of 2.
     pub total_cycles: u64,
 
+    /// The `(index, cycles, po2)` of each [Segment], where cycles are the
+    /// instruction cycles the segment actually used.
+    pub(crate) segment_cycles: Vec<(usize, u64, usize)>,
+
+    /// The bytes read by the guest from stdin, if recording was enabled with
+    /// [ExecutorEnvBuilder::record_input](crate::ExecutorEnvBuilder::record_input).
+    pub(crate) consumed_input: Vec<u8>,
+
     /// The system state of the initial [MemoryImage].
     pub pre_state: SystemState,
 
@@ -148,6 +164,8 @@ impl Session {
         assumptions: Vec<(Assumption, AssumptionReceipt)>,
         user_cycles: u64,
         total_cycles: u64,
+        segment_cycles: Vec<(usize, u64, usize)>,
+        consumed_input: Vec<u8>,
         pre_state: SystemState,
         post_state: SystemState,
         pending_zkrs: Vec<ProveZkrRequest>,
@@ -162,6 +180,8 @@ impl Session {
             hooks: Vec::new(),
             user_cycles,
             total_cycles,
+            segment_cycles,
+            consumed_input,
             pre_state,
             post_state,
             pending_zkrs,
@@ -228,6 +248,47 @@ impl Session {
         })
     }
 
+    /// Returns the `(segment_index, cycles, po2)` for each [Segment] in this
+    /// [Session].
+    ///
+    /// The cycles are the instruction cycles the [Segment] actually used, so
+    /// they sum to [Session::user_cycles]. Comparing them with `1 << po2` shows
+    /// how much of each segment's budget went unused.
+    pub fn segment_cycles(&self) -> Vec<(usize, u64, usize)> {
+        self.segment_cycles.clone()
+    }
+
+    /// Returns the bytes the guest read from stdin during this [Session].
+    ///
+    /// This is empty unless input recording was enabled with
+    /// [ExecutorEnvBuilder::record_input](crate::ExecutorEnvBuilder::record_input).
+    pub fn consumed_input(&self) -> &[u8] {
+        &self.consumed_input
+    }
+
+    /// Returns a rough estimate, in bytes, of the peak memory needed to prove this [Session]
+    /// with the given [ProverOpts].
+    ///
+    /// Segments are proven one at a time, and the prover allocates for a segment's full `1 << po2`
+    /// trace, so the estimate scales with the padded cycles of the largest segment. If `opts` requests a receipt kind other than composite, the recursion prover
+    /// (which runs at [RECURSION_PO2]) is also accounted for. All supported hash functions use
+    /// 32-byte digests, so `opts.hashfn` does not currently change the estimate.
+    ///
+    /// This is an estimate, not a guarantee: actual usage depends on the prover backend and the
+    /// allocator.
+    pub fn estimate_proving_memory(&self, opts: &ProverOpts) -> u64 {
+        let mut cycles = self
+            .segment_cycles
+            .iter()
+            .map(|(_, _, po2)| 1u64 << po2)
+            .max()
+            .unwrap_or(0);
+        if opts.receipt_kind != ReceiptKind::Composite {
+            cycles = cycles.max(1 << RECURSION_PO2);
+        }
+        cycles * PROVING_BYTES_PER_CYCLE
+    }
+
     /// Log cycle information for this [Session].
     ///
     /// This logs the total and user cycles for this [Session] at the INFO level.
@@ -132,7 +132,8 @@ pub use {
         client::{
             env::{ExecutorEnv, ExecutorEnvBuilder},
             prove::{
-                default_executor, default_prover, external::ExternalProver, Executor, Prover,
+                default_executor, default_prover, external::ExternalProver, prover_from_config,
+                try_default_prover, Executor, Prover, ProverBackend, ProverConfig, ProverHashFn,
                 ProverOpts, ReceiptKind,
             },
         },
@@ -157,10 +158,10 @@ pub use {
 };
 
 pub use receipt::{
-    AssumptionReceipt, CompositeReceipt, CompositeReceiptVerifierParameters, FakeReceipt,
-    InnerAssumptionReceipt, InnerReceipt, Journal, Receipt, ReceiptMetadata, SegmentReceipt,
-    SegmentReceiptVerifierParameters, SuccinctReceipt, SuccinctReceiptVerifierParameters,
-    VerifierContext, DEFAULT_MAX_PO2,
+    verify_batch, AssumptionReceipt, BatchVerifyError, CompositeReceipt,
+    CompositeReceiptVerifierParameters, FakeReceipt, InnerAssumptionReceipt, InnerReceipt, Journal,
+    Receipt, ReceiptMetadata, SegmentReceipt, SegmentReceiptVerifierParameters, SuccinctReceipt,
+    SuccinctReceiptVerifierParameters, VerifierContext, DEFAULT_MAX_PO2,
 };
 //#[cfg(any(not(target_os = "zkvm"), feature = "std"))]
 pub use receipt::{Groth16Receipt, Groth16ReceiptVerifierParameters};
@@ -194,6 +194,47 @@ impl Receipt {
         Ok(())
     }
 
+    /// Verify that this receipt proves a successful execution of the zkVM from the given
+    /// `image_id`, and that it was produced by one of the `allowed` recursion programs.
+    ///
+    /// In addition to the checks done by [Receipt::verify], this ensures the control ID of the
+    /// recursion program that produced the receipt is in `allowed`, returning
+    /// [VerificationError::ControlIdNotAllowed] if it is not. This lets a verifier pin a
+    /// specific recursion circuit rather than accepting any program in the allowed control root.
+    /// Only succinct receipts carry a control ID; any other kind is rejected with
+    /// [VerificationError::ReceiptFormatError].
+    pub fn verify_with_allowed_ids(
+        &self,
+        image_id: impl Into<Digest>,
+        allowed: &[Digest],
+    ) -> Result<(), VerificationError> {
+        let InnerReceipt::Succinct(inner) = &self.inner else {
+            return Err(VerificationError::ReceiptFormatError);
+        };
+        self.verify(image_id)?;
+        if !allowed.contains(&inner.control_id) {
+            return Err(VerificationError::ControlIdNotAllowed {
+                control_id: inner.control_id,
+            });
+        }
+        Ok(())
+    }
+
+    /// Verify that every receipt in `receipts` proves a successful execution of the zkVM from the
+    /// given `image_id`.
+    ///
+    /// Verification stops at the first receipt that fails, and the returned [BatchVerifyError]
+    /// holds only that receipt's index and error. With the `prove` feature enabled, receipts are
+    /// verified in parallel, the lowest failing index is reported, and the remaining work is
+    /// cancelled. Use [verify_batch] to check every receipt and collect all failures instead.
+    pub fn verify_batch(
+        receipts: &[Receipt],
+        image_id: impl Into<Digest>,
+    ) -> Result<(), BatchVerifyError> {
+        let image_id = image_id.into();
+        verify_batch_by(receipts.len(), true, |idx| (&receipts[idx], image_id))
+    }
+
     /// Verify the integrity of this receipt, ensuring the claim and journal
     /// are attested to by the seal.
     ///
@@ -257,10 +298,50 @@ impl Receipt {
         self.inner.claim()
     }
 
+    /// Digests of the assumption claims this receipt depends on, as recorded in its claim.
+    ///
+    /// A receipt with no output has no assumptions. Returns
+    /// [VerificationError::ReceiptFormatError] if the claim is pruned such that the assumptions
+    /// cannot be read.
+    pub fn assumption_digests(&self) -> Result<Vec<Digest>, VerificationError> {
+        let claim = self.claim()?;
+        let claim = claim
+            .as_value()
+            .map_err(|_| VerificationError::ReceiptFormatError)?;
+        let Some(output) = claim
+            .output
+            .as_value()
+            .map_err(|_| VerificationError::ReceiptFormatError)?
+        else {
+            return Ok(Vec::new());
+        };
+        let assumptions = output
+            .assumptions
+            .as_value()
+            .map_err(|_| VerificationError::ReceiptFormatError)?;
+        assumptions
+            .iter()
+            .map(|assumption| {
+                assumption
+                    .as_value()
+                    .map(|assumption| assumption.claim)
+                    .map_err(|_| VerificationError::ReceiptFormatError)
+            })
+            .collect()
+    }
+
     /// Total number of bytes used by the seals of this receipt.
     pub fn seal_size(&self) -> usize {
         self.inner.seal_size()
     }
+
+    /// Number of segment receipts in this receipt, or `None` if it is not a composite receipt.
+    pub fn segment_count(&self) -> Option<usize> {
+        self.inner
+            .composite()
+            .ok()
+            .map(|receipt| receipt.segments.len())
+    }
 }
 
 /// A record of the public commitments for a proven zkVM execution.
@@ -792,6 +873,89 @@ impl Default for VerifierContext {
     }
 }
 
+/// Error returned by [verify_batch], listing every receipt in the batch that failed verification.
+#[derive(Debug)]
+pub struct BatchVerifyError {
+    /// The index within the batch and the verification error of each receipt that failed.
+    pub failed: Vec<(usize, VerificationError)>,
+}
+
+impl core::fmt::Display for BatchVerifyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} receipt(s) failed verification:", self.failed.len())?;
+        for (idx, err) in &self.failed {
+            write!(f, " [{idx}] {err};")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchVerifyError {}
+
+/// Verify a batch of receipts, each against its own image ID.
+///
+/// Unlike calling [Receipt::verify] in a loop, this does not stop at the first failure. Every
+/// receipt is verified, and the index of each one that failed is reported in the returned
+/// [BatchVerifyError]. Verification uses the default [VerifierContext], which is constructed once
+/// per worker rather than once per receipt. With the `prove` feature enabled, receipts are
+/// verified in parallel.
+pub fn verify_batch(receipts: &[(Receipt, Digest)]) -> Result<(), BatchVerifyError> {
+    verify_batch_by(receipts.len(), false, |idx| {
+        let (receipt, image_id) = &receipts[idx];
+        (receipt, *image_id)
+    })
+}
+
+/// Verify `count` receipts, where `item` returns the receipt at a given index along with the image
+/// ID to check it against. If `first_only` is set, stop at the lowest failing index.
+fn verify_batch_by<'a>(
+    count: usize,
+    first_only: bool,
+    item: impl Fn(usize) -> (&'a Receipt, Digest) + Sync,
+) -> Result<(), BatchVerifyError> {
+    let verify = |ctx: &mut VerifierContext, idx: usize| {
+        let (receipt, image_id) = item(idx);
+        receipt
+            .verify_with_context(ctx, image_id)
+            .err()
+            .map(|err| (idx, err))
+    };
+
+    #[cfg(feature = "prove")]
+    let failed: Vec<_> = {
+        use rayon::prelude::*;
+        let results = (0..count)
+            .into_par_iter()
+            .map_init(VerifierContext::default, verify);
+        if first_only {
+            results
+                .find_map_first(|failure| failure)
+                .into_iter()
+                .collect()
+        } else {
+            results.flatten().collect()
+        }
+    };
+
+    #[cfg(not(feature = "prove"))]
+    let failed: Vec<_> = {
+        let mut ctx = VerifierContext::default();
+        let mut results = (0..count).filter_map(|idx| verify(&mut ctx, idx));
+        if first_only {
+            results.next().into_iter().collect()
+        } else {
+            results.collect()
+        }
+    };
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(BatchVerifyError { failed })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{FakeReceipt, InnerReceipt, Receipt};
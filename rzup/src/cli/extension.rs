@@ -14,7 +14,11 @@
 
 use std::path::PathBuf;
 
-use crate::{cli, extension::Extension, utils::find_installed_extensions};
+use crate::{
+    cli,
+    extension::Extension,
+    utils::{find_installed_extensions, rzup_home},
+};
 use anyhow::Result;
 use clap::Subcommand;
 use regex::Regex;
@@ -38,6 +42,9 @@ pub enum ExtensionSubcmd {
         /// Force installation, removing existing directories
         #[arg(short, long)]
         force: bool,
+        /// Install from a local release tarball instead of downloading it
+        #[arg(long, value_name = "TARBALL", requires = "version")]
+        from: Option<PathBuf>,
     },
     /// Use an installed extension version
     Use {
@@ -50,6 +57,8 @@ pub enum ExtensionSubcmd {
     Uninstall {
         /// The extension to uninstall (e.g., cargo-risczero)
         extension: Extension,
+        /// Remove this installed version (e.g., v1.0.1) instead of only unlinking the extension
+        version: Option<String>,
     },
 }
 
@@ -59,6 +68,15 @@ pub async fn handler(subcmd: ExtensionSubcmd) -> Result<()> {
             extension,
             version,
             force,
+            from: Some(tarball),
+        } => {
+            extension.install_from_path(&tarball, &version.expect("clap enforces --version"), force)
+        }
+        ExtensionSubcmd::Install {
+            extension,
+            version,
+            force,
+            from: None,
         } => extension.install(version.as_deref(), force).await,
         ExtensionSubcmd::List => {
             let extensions = find_installed_extensions()?;
@@ -71,17 +89,24 @@ pub async fn handler(subcmd: ExtensionSubcmd) -> Result<()> {
             let extension_path = parse_extenstion_version(extension, version)?;
             extension.link(&extension_path)
         }
-        ExtensionSubcmd::Uninstall { extension } => extension.unlink(),
+        ExtensionSubcmd::Uninstall {
+            extension,
+            version: Some(version),
+        } => extension.uninstall(&version, &rzup_home()?.join("extensions")),
+        ExtensionSubcmd::Uninstall {
+            extension,
+            version: None,
+        } => extension.unlink(),
     }
 }
 
 fn parse_extenstion_version(extension: Extension, version: String) -> Result<PathBuf> {
     let extensions = find_installed_extensions()?;
-    let version_pattern = match extension {
-        Extension::CargoRiscZero => {
-            format!(r"^(v)?{}(?:-.+)?$", regex::escape(&version))
-        }
-    };
+    let version_pattern = format!(
+        r"^(v)?{}-{}$",
+        regex::escape(&version),
+        regex::escape(extension.to_str())
+    );
 
     let re = Regex::new(&version_pattern)?;
 
@@ -40,6 +40,10 @@ pub static INSTALL_HELP: &str = r"Discussion:
     If given a toolchain or extension argument then `install` installs the
     specified toolchain or extension.";
 
+pub static LIST_HELP: &str = r"Discussion:
+    Lists the installed versions of each extension, marking the version that
+    is currently linked into `~/.cargo/bin` as active.";
+
 pub static DEFAULT_HELP: &str = r"Discussion:
     Sets the default (active) toolchain to the specified toolchain.
 
@@ -30,7 +30,7 @@ pub async fn handler(opts: InstallOpts) -> Result<()> {
         // Install all default
         Toolchain::Rust.install(None, opts.force).await?;
         Toolchain::Cpp.install(None, opts.force).await?;
-        Extension::CargoRiscZero.install(None, opts.force).await?;
+        Extension::CARGO_RISCZERO.install(None, opts.force).await?;
     } else {
         let name = opts.name.unwrap();
         let version = opts.version.as_deref();
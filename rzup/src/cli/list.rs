@@ -0,0 +1,40 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use termcolor::{ColorChoice, StandardStream};
+
+use crate::{extension::EXTENSIONS, pretty_header, pretty_msg, pretty_msgln, utils::rzup_home};
+
+pub fn handler() -> Result<()> {
+    let mut stdout = StandardStream::stdout(ColorChoice::Always);
+    let root_dir = rzup_home()?.join("extensions");
+
+    for extension in EXTENSIONS {
+        let versions = extension.list_installed(&root_dir)?;
+        if versions.is_empty() {
+            continue;
+        }
+
+        pretty_header!(&mut stdout, extension.name);
+        for installed in versions {
+            if installed.active {
+                println!("{} (active)", installed.version);
+            } else {
+                println!("{}", installed.version);
+            }
+        }
+    }
+    Ok(())
+}
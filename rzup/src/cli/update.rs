@@ -39,7 +39,7 @@ pub async fn handler(opts: UpdateOpts) -> Result<()> {
     if opts.toolchain.is_none() && opts.extension.is_none() {
         Toolchain::Rust.install(None, opts.force).await?;
         Toolchain::Cpp.install(None, opts.force).await?;
-        Extension::CargoRiscZero.install(None, opts.force).await?;
+        Extension::CARGO_RISCZERO.install(None, opts.force).await?;
     } else {
         // Update specific toolchain or extension if provided
         if let Some(toolchain) = opts.toolchain {
@@ -20,8 +20,9 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use flate2::bufread::GzDecoder;
+use sha2::{Digest as _, Sha256};
 use tar::Archive;
 use tempfile::tempdir;
 
@@ -33,37 +34,117 @@ use crate::{
     verbose_msg,
 };
 
-#[derive(Debug, Clone, Copy, Default)]
-pub enum Extension {
-    #[default]
-    CargoRiscZero,
+/// A tool that rzup installs from the GitHub releases of a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Extension {
+    /// Name of the extension, as given on the command line.
+    pub name: &'static str,
+    /// GitHub API URL of the repository whose releases provide the extension.
+    pub repo_url: &'static str,
+    /// Binaries in the release archive that are linked into `~/.cargo/bin`.
+    pub binaries: &'static [&'static str],
+}
+
+/// The extensions rzup knows how to install.
+pub const EXTENSIONS: &[Extension] = &[Extension::CARGO_RISCZERO];
+
+/// A version of an extension found on disk by [Extension::list_installed].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledVersion {
+    /// Release tag of the version, e.g. `v1.1.0`.
+    pub version: String,
+    /// Whether the binaries in `~/.cargo/bin` currently link to this version.
+    pub active: bool,
+}
+
+impl Default for Extension {
+    fn default() -> Self {
+        Extension::CARGO_RISCZERO
+    }
 }
 
 impl FromStr for Extension {
     type Err = RzupError;
 
     fn from_str(input: &str) -> Result<Extension, Self::Err> {
-        match input.to_lowercase().as_str() {
-            "cargo-risczero" => Ok(Extension::CargoRiscZero),
-            _ => Err(RzupError::InvalidToolchain),
-        }
+        let input = input.to_lowercase();
+        EXTENSIONS
+            .iter()
+            .find(|extension| extension.name == input)
+            .copied()
+            .ok_or(RzupError::InvalidToolchain)
+    }
+}
+
+/// Check that `content` hashes to the hex-encoded SHA-256 digest `expected`.
+fn verify_sha256(content: &[u8], expected: &str) -> Result<(), RzupError> {
+    let actual = hex::encode(Sha256::digest(content));
+    if !actual.eq_ignore_ascii_case(expected.trim()) {
+        return Err(RzupError::Other(format!(
+            "Checksum mismatch for downloaded asset: expected sha256 {}, got {actual}",
+            expected.trim()
+        )));
     }
+    Ok(())
+}
+
+/// Sort key that orders release tags like `v1.9.0` before `v1.10.0`.
+fn version_key(version: &str) -> Vec<Option<u64>> {
+    version
+        .trim_start_matches('v')
+        .split(['.', '-'])
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Delete an existing `extension_dir` if `force` is set.
+///
+/// Returns true if an existing installation was found and should be kept.
+fn keep_existing(extension_dir: &Path, force: bool) -> Result<bool> {
+    if !extension_dir.is_dir() {
+        return Ok(false);
+    }
+    if !force {
+        return Ok(true);
+    }
+
+    info_msg!(format!(
+        "Extension path {} already exists - deleting existing files!",
+        extension_dir.display()
+    ));
+
+    verbose_msg!(format!("deleting {}", extension_dir.display()));
+
+    fs::remove_dir_all(extension_dir)?;
+    Ok(false)
+}
+
+fn cargo_bin_dir() -> Result<PathBuf> {
+    Ok(dirs::home_dir()
+        .ok_or_else(|| anyhow!("Could not determine home directory"))?
+        .join(".cargo/bin"))
 }
 
 impl Extension {
+    pub const CARGO_RISCZERO: Extension = Extension {
+        name: "cargo-risczero",
+        repo_url: "https://api.github.com/repos/risc0/risc0",
+        binaries: &["cargo-risczero", "r0vm"],
+    };
+
     pub fn to_str(self) -> &'static str {
-        match self {
-            Extension::CargoRiscZero => "cargo-risczero",
-        }
+        self.name
+    }
+
+    /// Directory that `version` of this extension is installed to under `root_dir`.
+    fn version_dir(&self, root_dir: &Path, version: &str) -> PathBuf {
+        root_dir.join(format!("{version}-{}", self.name))
     }
 
     fn api_url(&self, tag: Option<&str>) -> String {
-        let base_url = match self {
-            Extension::CargoRiscZero => "https://api.github.com/repos/risc0/risc0/releases",
-        };
         match tag {
-            Some(tag) => format!("{}/tags/{}", base_url, tag),
-            None => format!("{}/latest", base_url),
+            Some(tag) => format!("{}/releases/tags/{}", self.repo_url, tag),
+            None => format!("{}/releases/latest", self.repo_url),
         }
     }
 
@@ -78,7 +159,7 @@ impl Extension {
 
         if res.status() == 403 {
             return Err(RzupError::Other(
-                "Rate limited by GitHub API. Please set a GitHub token in the GITHUB_TOKEN environment variable."
+                "Rate limited by GitHub API. Please set a GitHub token in the GITHUB_TOKEN or GH_TOKEN environment variable."
                     .into(),
             ).into());
         }
@@ -99,9 +180,9 @@ impl Extension {
 
         let temp_dir = tempdir()?;
 
-        let temp_file_path = match self {
-            Extension::CargoRiscZero => temp_dir.path().join("tmp_cargo_risczero_extension.tgz"),
-        };
+        let temp_file_path = temp_dir
+            .path()
+            .join(format!("tmp_{}_extension.tgz", self.name.replace('-', "_")));
 
         let release_info = self.release_info(tag).await?;
         let Some(asset) = release_info.assets.get(&target) else {
@@ -113,20 +194,7 @@ impl Extension {
         let extension_dir =
             extensions_root_dir.join(format!("{}-{}", release_info.tag_name, self.to_str()));
 
-        // Remove directory if it exists and force is set
-        if extension_dir.is_dir() && force {
-            info_msg!(format!(
-                "Extension path {} already exists - deleting existing files!",
-                extension_dir.display()
-            ));
-
-            verbose_msg!(format!("deleting {}", extension_dir.display()));
-
-            fs::remove_dir_all(&extension_dir)?;
-        }
-
-        // Skip download if directory already exists and force is not set
-        if extension_dir.is_dir() && !force {
+        if keep_existing(&extension_dir, force)? {
             info_msg!(format!(
                 "Extension path {} already exists - skipping download.",
                 extension_dir.display()
@@ -157,6 +225,29 @@ impl Extension {
         let mut file = fs::File::create(&temp_file_path)?;
         let content = response.bytes().await?;
 
+        let expected_sha256 = match (asset.sha256(), &asset.checksum_url) {
+            (Some(digest), _) => Some(digest.to_string()),
+            (None, Some(url)) => {
+                verbose_msg!(format!("Requesting checksum from {url}"));
+                let checksum = client
+                    .get(url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await?;
+                checksum.split_whitespace().next().map(str::to_string)
+            }
+            (None, None) => None,
+        };
+        match expected_sha256 {
+            Some(expected) => {
+                verbose_msg!(format!("Verifying sha256 checksum {expected}"));
+                verify_sha256(&content, &expected)?;
+            }
+            None => verbose_msg!("No checksum published for asset - skipping verification"),
+        }
+
         verbose_msg!(format!(
             "Writing contents to file {}",
             temp_file_path.display()
@@ -164,100 +255,206 @@ impl Extension {
 
         file.write_all(&content)?;
 
-        let tarball = fs::File::open(temp_file_path)?;
+        self.unpack(&temp_file_path, &extension_dir)?;
 
-        match self {
-            Extension::CargoRiscZero => {
-                info_msg!(format!("Extracting {} extension...", self.to_str()));
+        Ok(extension_dir)
+    }
 
-                let decoder = GzDecoder::new(BufReader::new(tarball));
-                let mut archive = Archive::new(decoder);
+    /// Unpack the gzipped `tarball` into `extension_dir` and make the binaries executable.
+    fn unpack(&self, tarball: &Path, extension_dir: &Path) -> Result<()> {
+        let tarball = fs::File::open(tarball)?;
 
-                verbose_msg!(format!(
-                    "Unpacking {} to {}",
-                    self.to_str(),
-                    &extension_dir.display()
-                ));
+        info_msg!(format!("Extracting {} extension...", self.to_str()));
+
+        let decoder = GzDecoder::new(BufReader::new(tarball));
+        let mut archive = Archive::new(decoder);
+
+        verbose_msg!(format!(
+            "Unpacking {} to {}",
+            self.to_str(),
+            &extension_dir.display()
+        ));
+
+        archive.unpack(extension_dir)?;
+
+        verbose_msg!("Setting extension permissons to 0o755");
+
+        for binary in self.binaries {
+            let binary_path = extension_dir.join(binary);
+            let mut perms = fs::metadata(&binary_path)?.permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(&binary_path, perms)?;
+        }
+
+        Ok(())
+    }
 
-                archive.unpack(&extension_dir)?;
-                let binary_path = extension_dir.join("cargo-risczero");
+    /// Remove `version` of this extension from `root_dir`.
+    ///
+    /// If the removed version was the active one, the newest remaining version
+    /// is linked in its place, or the binaries are unlinked if none remain.
+    pub fn uninstall(&self, version: &str, root_dir: &Path) -> Result<()> {
+        self.uninstall_in(version, root_dir, &cargo_bin_dir()?)
+    }
+
+    fn uninstall_in(&self, version: &str, root_dir: &Path, bin_dir: &Path) -> Result<()> {
+        let mut installed = self.list_installed_in(root_dir, bin_dir)?;
+        let Some(idx) = installed.iter().position(|installed| {
+            installed.version == version || installed.version.strip_prefix('v') == Some(version)
+        }) else {
+            bail!("{} {version} is not installed", self.name);
+        };
+        let removed = installed.remove(idx);
 
-                verbose_msg!("Setting extension permissons to 0o755");
+        let extension_dir = self.version_dir(root_dir, &removed.version);
+        info_msg!(format!("Removing {}", extension_dir.display()));
+        fs::remove_dir_all(&extension_dir)?;
 
-                let mut perms = fs::metadata(&binary_path)?.permissions();
-                perms.set_mode(0o755);
-                fs::set_permissions(&binary_path, perms)?;
+        if removed.active {
+            match installed.last() {
+                Some(fallback) => {
+                    self.link_into(&self.version_dir(root_dir, &fallback.version), bin_dir)?
+                }
+                None => self.unlink_from(bin_dir)?,
             }
         }
+        Ok(())
+    }
+
+    /// Install `version` of this extension from a local release tarball, without
+    /// contacting GitHub.
+    pub fn install_from_path(&self, tarball: &Path, version: &str, force: bool) -> Result<()> {
+        let root_dir = rzup_home()?;
+
+        let lockfile_path = root_dir.join("ext-lock");
+        let _lock = flock(&lockfile_path)?;
+
+        let extensions_root_dir = root_dir.join("extensions");
+
+        let extension_dir = self.unpack_version(tarball, version, &extensions_root_dir, force)?;
+        self.link(&extension_dir)?;
+        Ok(())
+    }
+
+    /// Unpack `tarball` into the directory for `version` under `extensions_root_dir`.
+    fn unpack_version(
+        &self,
+        tarball: &Path,
+        version: &str,
+        extensions_root_dir: &Path,
+        force: bool,
+    ) -> Result<PathBuf> {
+        let extension_dir = self.version_dir(extensions_root_dir, version);
+
+        if keep_existing(&extension_dir, force)? {
+            info_msg!(format!(
+                "Extension path {} already exists - skipping unpack.",
+                extension_dir.display()
+            ));
+            return Ok(extension_dir);
+        }
 
+        self.unpack(tarball, &extension_dir)?;
         Ok(extension_dir)
     }
 
     pub fn link(&self, dir: &Path) -> Result<()> {
-        match self {
-            Extension::CargoRiscZero => {
-                let cargo_bin_dir = dirs::home_dir()
-                    .ok_or_else(|| anyhow!("Could not determine home directory"))?
-                    .join(".cargo/bin");
+        self.link_into(dir, &cargo_bin_dir()?)
+    }
 
-                self.unlink()?;
+    /// Symlink each of this extension's binaries in `dir` into `bin_dir`.
+    fn link_into(&self, dir: &Path, bin_dir: &Path) -> Result<()> {
+        self.unlink_from(bin_dir)?;
 
-                let cargo_risczero_path = dir.join("cargo-risczero");
-                let r0vm_path = dir.join("r0vm");
+        for binary in self.binaries {
+            let binary_path = dir.join(binary);
+            let binary_link = bin_dir.join(binary);
 
-                let cargo_risczero_link = cargo_bin_dir.join("cargo-risczero");
-                let r0vm_link = cargo_bin_dir.join("r0vm");
+            verbose_msg!(format!(
+                "Creating symlinks from {} to {}",
+                binary_path.display(),
+                binary_link.display()
+            ));
+
+            // Create new symlinks
+            std::os::unix::fs::symlink(binary_path, binary_link)
+                .with_context(|| format!("Failed to create symlink for {binary}"))?;
+        }
+        info_msg!(format!(
+            "Symlinks for {} created successfully at {}",
+            self.binaries.join(" and "),
+            bin_dir.display()
+        ));
+        Ok(())
+    }
+
+    pub fn unlink(&self) -> Result<()> {
+        self.unlink_from(&cargo_bin_dir()?)
+    }
 
+    /// Remove the symlinks to this extension's binaries from `bin_dir`.
+    fn unlink_from(&self, bin_dir: &Path) -> Result<()> {
+        for binary in self.binaries {
+            let binary_link = bin_dir.join(binary);
+
+            // Remove existing symlinks if they exist
+            if fs::symlink_metadata(&binary_link).is_ok() {
                 verbose_msg!(format!(
-                    "Creating symlinks from {} to {}",
-                    cargo_risczero_path.display(),
-                    cargo_risczero_link.display()
+                    "Removing {binary} symlink at {}",
+                    binary_link.display()
                 ));
 
-                // Create new symlinks
-                std::os::unix::fs::symlink(cargo_risczero_path, cargo_risczero_link)
-                    .context("Failed to create symlink for cargo-risczero")?;
-                std::os::unix::fs::symlink(r0vm_path, r0vm_link)
-                    .context("Failed to create symlink for r0vm")?;
-                info_msg!(format!(
-                    "Symlinks for cargo-risczero and r0vm created successfully at {}",
-                    cargo_bin_dir.display()
-                ));
+                fs::remove_file(&binary_link)
+                    .with_context(|| format!("Failed to remove existing {binary} symlink"))?;
+                info_msg!(format!("Symlinks for {binary} removed successfully"));
             }
         }
         Ok(())
     }
 
-    pub fn unlink(&self) -> Result<()> {
-        match self {
-            Extension::CargoRiscZero => {
-                let cargo_bin_dir = dirs::home_dir()
-                    .ok_or_else(|| anyhow!("Could not determine home directory"))?
-                    .join(".cargo/bin");
-
-                let cargo_risczero_link = cargo_bin_dir.join("cargo-risczero");
-                let r0vm_link = cargo_bin_dir.join("r0vm");
-
-                // Remove existing symlinks if they exist
-                if fs::symlink_metadata(&cargo_risczero_link).is_ok() {
-                    verbose_msg!(format!(
-                        "Removing cargo-risczero symlink at {}",
-                        cargo_risczero_link.display()
-                    ));
-
-                    fs::remove_file(&cargo_risczero_link)
-                        .context("Failed to remove existing cargo-risczero symlink")?;
-                    info_msg!("Symlinks for cargo-risczero removed successfully");
-                }
-                if fs::symlink_metadata(&r0vm_link).is_ok() {
-                    verbose_msg!(format!("Removing r0vm symlink at {}", r0vm_link.display()));
-                    fs::remove_file(&r0vm_link)
-                        .context("Failed to remove existing r0vm symlink")?;
-                    info_msg!("Symlinks for r0vm removed successfully");
-                }
+    /// List the versions of this extension installed under `root_dir`, sorted oldest first.
+    pub fn list_installed(&self, root_dir: &Path) -> Result<Vec<InstalledVersion>> {
+        self.list_installed_in(root_dir, &cargo_bin_dir()?)
+    }
+
+    fn list_installed_in(&self, root_dir: &Path, bin_dir: &Path) -> Result<Vec<InstalledVersion>> {
+        let mut versions = Vec::new();
+        if !root_dir.is_dir() {
+            return Ok(versions);
+        }
+
+        // The version directory that the first binary currently links to, if any.
+        let active_dir = self
+            .binaries
+            .first()
+            .and_then(|binary| fs::read_link(bin_dir.join(binary)).ok())
+            .and_then(|target| target.parent().map(Path::to_path_buf));
+
+        let suffix = format!("-{}", self.name);
+        for entry in fs::read_dir(root_dir)? {
+            let path = entry?.path();
+            if !path.is_dir() {
+                continue;
             }
+            let Some(version) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(&suffix))
+            else {
+                continue;
+            };
+            verbose_msg!(format!("Found {} {version}", self.name));
+            versions.push(InstalledVersion {
+                version: version.to_string(),
+                active: active_dir.as_deref() == Some(path.as_path()),
+            });
         }
-        Ok(())
+        versions.sort_by(|a, b| {
+            version_key(&a.version)
+                .cmp(&version_key(&b.version))
+                .then_with(|| a.version.cmp(&b.version))
+        });
+        Ok(versions)
     }
 
     pub async fn install(&self, tag: Option<&str>, force: bool) -> Result<()> {
@@ -271,14 +468,234 @@ impl Extension {
 
         let extensions_root_dir = root_dir.join("extensions");
 
-        match self {
-            Extension::CargoRiscZero => {
-                let cargo_risczero_path = self
-                    .download(target, tag, &extensions_root_dir, force)
-                    .await?;
-                self.link(&cargo_risczero_path)?;
+        let extension_dir = self
+            .download(target, tag, &extensions_root_dir, force)
+            .await?;
+        self.link(&extension_dir)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, os::unix::fs::PermissionsExt, path::Path};
+
+    use flate2::{write::GzEncoder, Compression};
+    use sha2::{Digest as _, Sha256};
+    use tempfile::tempdir;
+
+    use super::{verify_sha256, Extension, InstalledVersion};
+
+    const FAKE: Extension = Extension {
+        name: "fake-tool",
+        repo_url: "https://api.github.com/repos/example/fake-tool",
+        binaries: &["fake-tool", "fake-helper"],
+    };
+
+    #[test]
+    fn registry_lookup() {
+        assert_eq!(
+            "cargo-risczero".parse::<Extension>().unwrap(),
+            Extension::CARGO_RISCZERO
+        );
+        assert_eq!(
+            "Cargo-RiscZero".parse::<Extension>().unwrap(),
+            Extension::CARGO_RISCZERO
+        );
+        assert!(FAKE.name.parse::<Extension>().is_err());
+        assert_eq!(
+            FAKE.api_url(Some("v1.0.0")),
+            "https://api.github.com/repos/example/fake-tool/releases/tags/v1.0.0"
+        );
+        assert_eq!(
+            FAKE.api_url(None),
+            "https://api.github.com/repos/example/fake-tool/releases/latest"
+        );
+    }
+
+    #[test]
+    fn link_and_unlink_binaries() {
+        let extension_dir = tempdir().unwrap();
+        let bin_dir = tempdir().unwrap();
+        for binary in FAKE.binaries {
+            fs::write(extension_dir.path().join(binary), b"").unwrap();
+        }
+
+        FAKE.link_into(extension_dir.path(), bin_dir.path())
+            .unwrap();
+        for binary in FAKE.binaries {
+            let target = fs::read_link(bin_dir.path().join(binary)).unwrap();
+            assert_eq!(target, extension_dir.path().join(binary));
+        }
+
+        // Relinking replaces the existing links.
+        FAKE.link_into(extension_dir.path(), bin_dir.path())
+            .unwrap();
+
+        FAKE.unlink_from(bin_dir.path()).unwrap();
+        for binary in FAKE.binaries {
+            assert!(fs::symlink_metadata(bin_dir.path().join(binary)).is_err());
+        }
+    }
+
+    #[test]
+    fn sha256_comparison() {
+        const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+        verify_sha256(b"abc", ABC_SHA256).unwrap();
+        verify_sha256(b"abc", &ABC_SHA256.to_uppercase()).unwrap();
+        verify_sha256(b"abc", &format!("{ABC_SHA256}\n")).unwrap();
+
+        let err = verify_sha256(b"abd", ABC_SHA256).unwrap_err().to_string();
+        assert!(
+            err.contains(&format!("expected sha256 {ABC_SHA256}")),
+            "{err}"
+        );
+        assert!(err.contains(&format!("got {}", hex::encode(Sha256::digest(b"abd")))));
+    }
+
+    #[test]
+    fn list_installed_versions() {
+        let root_dir = tempdir().unwrap();
+        let bin_dir = tempdir().unwrap();
+        for version in ["v1.10.0", "v1.2.0", "v1.9.1"] {
+            let extension_dir = root_dir.path().join(format!("{version}-{}", FAKE.name));
+            fs::create_dir(&extension_dir).unwrap();
+            for binary in FAKE.binaries {
+                fs::write(extension_dir.join(binary), b"").unwrap();
             }
         }
-        Ok(())
+        // Directories belonging to other extensions are ignored.
+        fs::create_dir(root_dir.path().join("v1.0.0-other-tool")).unwrap();
+
+        let active_dir = root_dir.path().join(format!("v1.9.1-{}", FAKE.name));
+        FAKE.link_into(&active_dir, bin_dir.path()).unwrap();
+
+        let installed = |version: &str, active| InstalledVersion {
+            version: version.to_string(),
+            active,
+        };
+        assert_eq!(
+            FAKE.list_installed_in(root_dir.path(), bin_dir.path())
+                .unwrap(),
+            vec![
+                installed("v1.2.0", false),
+                installed("v1.9.1", true),
+                installed("v1.10.0", false),
+            ]
+        );
+
+        FAKE.unlink_from(bin_dir.path()).unwrap();
+        let versions = FAKE
+            .list_installed_in(root_dir.path(), bin_dir.path())
+            .unwrap();
+        assert!(versions.iter().all(|version| !version.active));
+    }
+
+    fn write_tarball(path: &Path, binaries: &[&str]) {
+        let encoder = GzEncoder::new(fs::File::create(path).unwrap(), Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        for binary in binaries {
+            let contents = format!("#!/bin/sh\necho {binary}\n");
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, binary, contents.as_bytes())
+                .unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn unpack_local_tarball() {
+        let temp_dir = tempdir().unwrap();
+        let tarball = temp_dir.path().join("fake-tool.tgz");
+        write_tarball(&tarball, FAKE.binaries);
+
+        let root_dir = temp_dir.path().join("extensions");
+        let extension_dir = FAKE
+            .unpack_version(&tarball, "v1.0.0", &root_dir, false)
+            .unwrap();
+        assert_eq!(extension_dir, root_dir.join("v1.0.0-fake-tool"));
+        for binary in FAKE.binaries {
+            let mode = fs::metadata(extension_dir.join(binary))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+
+        // Without force, an existing installation is kept as is.
+        fs::remove_file(extension_dir.join(FAKE.binaries[1])).unwrap();
+        FAKE.unpack_version(&tarball, "v1.0.0", &root_dir, false)
+            .unwrap();
+        assert!(!extension_dir.join(FAKE.binaries[1]).exists());
+
+        FAKE.unpack_version(&tarball, "v1.0.0", &root_dir, true)
+            .unwrap();
+        assert!(extension_dir.join(FAKE.binaries[1]).exists());
+    }
+
+    fn install_versions(root_dir: &Path, versions: &[&str]) {
+        for version in versions {
+            let extension_dir = FAKE.version_dir(root_dir, version);
+            fs::create_dir_all(&extension_dir).unwrap();
+            for binary in FAKE.binaries {
+                fs::write(extension_dir.join(binary), b"").unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn uninstall_inactive_version() {
+        let root_dir = tempdir().unwrap();
+        let bin_dir = tempdir().unwrap();
+        install_versions(root_dir.path(), &["v1.0.0", "v1.1.0"]);
+        let active_dir = FAKE.version_dir(root_dir.path(), "v1.1.0");
+        FAKE.link_into(&active_dir, bin_dir.path()).unwrap();
+
+        FAKE.uninstall_in("1.0.0", root_dir.path(), bin_dir.path())
+            .unwrap();
+        assert!(!FAKE.version_dir(root_dir.path(), "v1.0.0").exists());
+        for binary in FAKE.binaries {
+            let target = fs::read_link(bin_dir.path().join(binary)).unwrap();
+            assert_eq!(target, active_dir.join(binary));
+        }
+
+        assert!(FAKE
+            .uninstall_in("v1.0.0", root_dir.path(), bin_dir.path())
+            .is_err());
+    }
+
+    #[test]
+    fn uninstall_active_version() {
+        let root_dir = tempdir().unwrap();
+        let bin_dir = tempdir().unwrap();
+        install_versions(root_dir.path(), &["v1.0.0", "v1.2.0", "v1.10.0"]);
+        FAKE.link_into(
+            &FAKE.version_dir(root_dir.path(), "v1.10.0"),
+            bin_dir.path(),
+        )
+        .unwrap();
+
+        // The newest remaining version becomes active.
+        FAKE.uninstall_in("v1.10.0", root_dir.path(), bin_dir.path())
+            .unwrap();
+        let fallback_dir = FAKE.version_dir(root_dir.path(), "v1.2.0");
+        for binary in FAKE.binaries {
+            let target = fs::read_link(bin_dir.path().join(binary)).unwrap();
+            assert_eq!(target, fallback_dir.join(binary));
+        }
+
+        // Removing the last versions leaves nothing linked.
+        FAKE.uninstall_in("v1.2.0", root_dir.path(), bin_dir.path())
+            .unwrap();
+        FAKE.uninstall_in("v1.0.0", root_dir.path(), bin_dir.path())
+            .unwrap();
+        for binary in FAKE.binaries {
+            assert!(fs::symlink_metadata(bin_dir.path().join(binary)).is_err());
+        }
     }
 }
@@ -85,6 +85,9 @@ enum RzupSubcmd {
     /// Check for updates to toolchains and extensions
     #[command(after_help = cli::help::CHECK_HELP)]
     Check,
+    /// List the installed extension versions
+    #[command(after_help = cli::help::LIST_HELP)]
+    List,
     /// Set the default toolchains
     #[command(after_help = cli::help::DEFAULT_HELP)]
     Default {
@@ -174,6 +177,7 @@ async fn run() -> Result<()> {
             .await
         }
         RzupSubcmd::Check => cli::check::handler().await,
+        RzupSubcmd::List => cli::list::handler(),
         RzupSubcmd::Default { toolchain, name } => cli::default::handler(toolchain, name),
         RzupSubcmd::Toolchain { subcmd } => cli::toolchain::handler(subcmd).await,
         RzupSubcmd::Extension { subcmd } => cli::extension::handler(subcmd).await,
@@ -28,13 +28,38 @@ pub struct GithubReleaseInfo {
 pub struct GithubAsset {
     pub browser_download_url: String,
     name: String,
+    /// Digest published by GitHub for this asset, e.g. `sha256:<hex>`.
+    #[serde(default)]
+    digest: Option<String>,
+    /// URL of a sibling `<name>.sha256` asset, if the release has one.
+    #[serde(skip)]
+    pub checksum_url: Option<String>,
+}
+
+impl GithubAsset {
+    /// The hex-encoded SHA-256 digest GitHub published for this asset, if any.
+    pub fn sha256(&self) -> Option<&str> {
+        self.digest.as_deref()?.strip_prefix("sha256:")
+    }
 }
 
 impl GithubReleaseInfo {
     fn parse_assets(assets: Vec<GithubAsset>) -> HashMap<Target, GithubAsset> {
+        let checksum_urls: HashMap<String, String> = assets
+            .iter()
+            .filter_map(|asset| {
+                let name = asset.name.strip_suffix(".sha256")?;
+                Some((name.to_string(), asset.browser_download_url.clone()))
+            })
+            .collect();
+
         let mut asset_map = HashMap::new();
-        for asset in assets {
+        for mut asset in assets {
+            if asset.name.ends_with(".sha256") {
+                continue;
+            }
             if let Some(target) = Self::extract_target_from_name(&asset.name) {
+                asset.checksum_url = checksum_urls.get(&asset.name).cloned();
                 asset_map.insert(target, asset);
             }
         }
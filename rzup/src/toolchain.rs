@@ -98,7 +98,7 @@ impl Toolchain {
 
         if res.status() == 403 {
             return Err(RzupError::Other(
-                "Rate limited by GitHub API. Please set a GitHub token in the GITHUB_TOKEN environment variable."
+                "Rate limited by GitHub API. Please set a GitHub token in the GITHUB_TOKEN or GH_TOKEN environment variable."
                     .into(),
             ).into());
         }
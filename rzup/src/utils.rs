@@ -17,7 +17,10 @@ use command::CommandExt;
 use fs2::FileExt;
 use lazy_static::lazy_static;
 use regex::Regex;
-use reqwest::{header::HeaderMap, Client};
+use reqwest::{
+    header::{HeaderMap, AUTHORIZATION},
+    Client,
+};
 use std::{
     env,
     fs::{self, File, OpenOptions},
@@ -295,11 +298,11 @@ pub struct UpdateInfo {
 
 /// Gets update information for the active cargo-risczero extension.
 async fn check_cargo_risczero_updates() -> Result<UpdateInfo, RzupError> {
-    let latest_cargo_risczero_version_info = Extension::CargoRiscZero.release_info(None).await?;
+    let latest_cargo_risczero_version_info = Extension::CARGO_RISCZERO.release_info(None).await?;
     let latest_cargo_risczero_version_tag = latest_cargo_risczero_version_info.tag_name;
     let installed_cargo_risczero_version = find_cargo_risczero_version()?;
     let installed_cargo_risczero_tag = format!("v{}", installed_cargo_risczero_version);
-    let installed_cargo_risczero_release_info = Extension::CargoRiscZero
+    let installed_cargo_risczero_release_info = Extension::CARGO_RISCZERO
         .release_info(Some(&installed_cargo_risczero_tag))
         .await?;
 
@@ -457,14 +460,26 @@ pub fn find_installed_extensions() -> Result<Vec<PathBuf>, RzupError> {
     Ok(extensions)
 }
 
-/// Creates and returns an HTTP client configured with GitHub token if available.
-pub fn http_client() -> Result<Client, RzupError> {
+/// Returns the GitHub token from `GITHUB_TOKEN`, falling back to `GH_TOKEN`.
+fn github_token() -> Option<String> {
+    ["GITHUB_TOKEN", "GH_TOKEN"]
+        .into_iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|token| !token.trim().is_empty())
+}
+
+/// Builds the default headers for GitHub requests, authenticating with `token` if given.
+fn github_headers(token: Option<&str>) -> Result<HeaderMap, RzupError> {
     let mut headers = HeaderMap::new();
-    if let Ok(token) = env::var("GITHUB_TOKEN") {
-        if !token.trim().is_empty() {
-            headers.insert("authorization", format!("Bearer {}", token).parse()?);
-        }
+    if let Some(token) = token {
+        headers.insert(AUTHORIZATION, format!("Bearer {}", token.trim()).parse()?);
     }
+    Ok(headers)
+}
+
+/// Creates and returns an HTTP client configured with GitHub token if available.
+pub fn http_client() -> Result<Client, RzupError> {
+    let headers = github_headers(github_token().as_deref())?;
 
     let client = Client::builder()
         .default_headers(headers)
@@ -474,3 +489,18 @@ pub fn http_client() -> Result<Client, RzupError> {
 
     Ok(client)
 }
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::AUTHORIZATION;
+
+    use super::github_headers;
+
+    #[test]
+    fn github_auth_header() {
+        let headers = github_headers(Some("abc123\n")).unwrap();
+        assert_eq!(headers.get(AUTHORIZATION).unwrap(), "Bearer abc123");
+
+        assert!(github_headers(None).unwrap().is_empty());
+    }
+}